@@ -2,7 +2,7 @@
 
 use serde::Deserialize;
 use seventy::{
-    builtins::{compare::*, email::*, string::*},
+    builtins::{compare::*, email::*, password::*, string::*},
     seventy,
 };
 
@@ -29,7 +29,7 @@ pub struct Username(String);
 
 #[seventy(
     upgrades(deserializable, inherent, unexposed),
-    validate(ascii, length::chars(gt(8)))
+    validate(ascii, length::chars(within(9..=128)), strength(ge(Score::Strong)))
 )]
 pub struct Password(String);
 
@@ -40,7 +40,7 @@ fn main() {
             {
                 "email": "   seventy70@example.com   ",
                 "username": "Seventy70   ",
-                "password": "p455w0rd70!"
+                "password": "Seven-Tea-Cups-70"
             }
         "#;
 
@@ -51,7 +51,7 @@ fn main() {
 
         assert_eq!(request.email.into_inner(), "seventy70@example.com");
         assert_eq!(request.username.into_inner(), "Seventy70");
-        assert_eq!(request.password.into_inner(), "p455w0rd70!");
+        assert_eq!(request.password.into_inner(), "Seven-Tea-Cups-70");
     }
 
     // Fails password validation (\u{7070} is not ASCII).
@@ -60,7 +60,21 @@ fn main() {
             {
                 "email": "   seventy70@example.com   ",
                 "username": "Seventy70   ",
-                "password": "p455w0rd\u{7070}!"
+                "password": "Seven-Tea-Cups-\u{7070}"
+            }
+        "#;
+
+        let result: Result<RegisterRequest, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    // Fails password validation (p455w0rd70! is a leetspeak common password).
+    {
+        let json = r#"
+            {
+                "email": "   seventy70@example.com   ",
+                "username": "Seventy70   ",
+                "password": "p455w0rd70!"
             }
         "#;
 
@@ -0,0 +1,366 @@
+//! Digest and checksum built-ins.
+//!
+//! The hex digest built-ins are both a [`Sanitizer`], which converts to
+//! lowercase, and a [`Validator`], which accepts either case.
+
+use data_encoding_util::BASE64;
+
+use crate::{
+    builtins::string::OwnedString,
+    core::{Sanitizer, Validator},
+};
+
+/// Checks if hex of the given length, in either case.
+fn is_hex(target: &str, len: usize) -> bool {
+    target.len() == len && target.bytes().all(|byte| byte.is_ascii_hexdigit())
+}
+
+/// Converts to lowercase.
+fn to_lowercase<T>(target: &mut T)
+where
+    T: OwnedString,
+{
+    target.with_string(|target| target.make_ascii_lowercase());
+}
+
+/// [`Sanitizer`] converts to lowercase. [`Validator`] checks if a hex MD5
+/// digest.
+///
+/// A hex MD5 digest is 32 hex digits.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::digest::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(md5_hex), validate(md5_hex))]
+/// pub struct ETag(String);
+///
+/// // Successfully constructed because the string is an MD5 digest, and converted to lowercase.
+/// assert_eq!(
+///     ETag::try_new("5456A19D6C110EAA6F65778CF4A98478").unwrap().into_inner(),
+///     "5456a19d6c110eaa6f65778cf4a98478"
+/// );
+///
+/// // Unsuccessfully constructed because the string is too short.
+/// assert!(ETag::try_new("5456a19d6c110eaa").is_err());
+/// ```
+pub struct md5_hex;
+
+impl<T> Sanitizer<T> for md5_hex
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        to_lowercase(target);
+    }
+}
+
+impl<T> Validator<T> for md5_hex
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        is_hex(target.as_ref(), 32)
+    }
+}
+
+/// [`Sanitizer`] converts to lowercase. [`Validator`] checks if a hex SHA-1
+/// digest.
+///
+/// A hex SHA-1 digest is 40 hex digits.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::digest::*, seventy, Newtype};
+///
+/// #[seventy(validate(sha1_hex))]
+/// pub struct Sha1(String);
+///
+/// // Successfully constructed because the string is a SHA-1 digest.
+/// assert!(Sha1::try_new("bb0c0c867fdca2a3a1d8fae0a0bc68d80c7b880c").is_ok());
+///
+/// // Unsuccessfully constructed because the string contains 'g'.
+/// assert!(Sha1::try_new("gb0c0c867fdca2a3a1d8fae0a0bc68d80c7b880c").is_err());
+/// ```
+pub struct sha1_hex;
+
+impl<T> Sanitizer<T> for sha1_hex
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        to_lowercase(target);
+    }
+}
+
+impl<T> Validator<T> for sha1_hex
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        is_hex(target.as_ref(), 40)
+    }
+}
+
+/// [`Sanitizer`] converts to lowercase. [`Validator`] checks if a hex SHA-256
+/// digest.
+///
+/// A hex SHA-256 digest is 64 hex digits.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::digest::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(sha256_hex), validate(sha256_hex))]
+/// pub struct ContentHash(String);
+///
+/// // Successfully constructed because the string is a SHA-256 digest, and converted to lowercase.
+/// assert_eq!(
+///     ContentHash::try_new("AB0E4B1A7839571E0278FDB4C49D6EA632BC9DF91968AF197D539B2DF976D4F9")
+///         .unwrap()
+///         .into_inner(),
+///     "ab0e4b1a7839571e0278fdb4c49d6ea632bc9df91968af197d539b2df976d4f9"
+/// );
+///
+/// // Unsuccessfully constructed because the string is a SHA-1 digest.
+/// assert!(ContentHash::try_new("bb0c0c867fdca2a3a1d8fae0a0bc68d80c7b880c").is_err());
+/// ```
+pub struct sha256_hex;
+
+impl<T> Sanitizer<T> for sha256_hex
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        to_lowercase(target);
+    }
+}
+
+impl<T> Validator<T> for sha256_hex
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        is_hex(target.as_ref(), 64)
+    }
+}
+
+/// [`Sanitizer`] converts to lowercase. [`Validator`] checks if a hex SHA-512
+/// digest.
+///
+/// A hex SHA-512 digest is 128 hex digits.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::digest::*, seventy, Newtype};
+///
+/// #[seventy(validate(sha512_hex))]
+/// pub struct Sha512(String);
+///
+/// // Successfully constructed because the string is a SHA-512 digest.
+/// assert!(Sha512::try_new(
+///     "c5e61ffce5f60b26d378ff7a200f3773ddcbaae205e19e94362fe89d23ecc8b0\
+///      5f3d170bef03cb030473f28a3dd720161f53e883b7d1a9e8d922256fa5fe1ac5"
+/// )
+/// .is_ok());
+///
+/// // Unsuccessfully constructed because the string is a SHA-256 digest.
+/// assert!(Sha512::try_new("ab0e4b1a7839571e0278fdb4c49d6ea632bc9df91968af197d539b2df976d4f9").is_err());
+/// ```
+pub struct sha512_hex;
+
+impl<T> Sanitizer<T> for sha512_hex
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        to_lowercase(target);
+    }
+}
+
+impl<T> Validator<T> for sha512_hex
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        is_hex(target.as_ref(), 128)
+    }
+}
+
+/// [`Sanitizer`] converts to lowercase. [`Validator`] checks if a Git object
+/// ID.
+///
+/// A `full` object ID is 40 hex digits (SHA-1) or 64 hex digits (SHA-256). An
+/// `abbreviated` object ID is 4 to 64 hex digits, which includes full object
+/// IDs.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::digest::*, seventy, Newtype};
+///
+/// #[seventy(validate(git_oid::full))]
+/// pub struct CommitId(String);
+///
+/// // Successfully constructed because the string is a full object ID.
+/// assert!(CommitId::try_new("bb0c0c867fdca2a3a1d8fae0a0bc68d80c7b880c").is_ok());
+///
+/// // Unsuccessfully constructed because the string is abbreviated.
+/// assert!(CommitId::try_new("bb0c0c8").is_err());
+///
+/// #[seventy(sanitize(git_oid::abbreviated), validate(git_oid::abbreviated))]
+/// pub struct Revision(String);
+///
+/// // Successfully constructed because the string is an abbreviated object ID, and converted to
+/// // lowercase.
+/// assert_eq!(Revision::try_new("BB0C0C8").unwrap().into_inner(), "bb0c0c8");
+///
+/// // Unsuccessfully constructed because the string is less than 4 hex digits.
+/// assert!(Revision::try_new("bb0").is_err());
+/// ```
+pub enum git_oid {
+    full,
+    abbreviated,
+}
+
+impl<T> Sanitizer<T> for git_oid
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        to_lowercase(target);
+    }
+}
+
+impl<T> Validator<T> for git_oid
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+
+        match self {
+            Self::full => is_hex(target, 40) || is_hex(target, 64),
+            Self::abbreviated => is_hex(target, target.len()) && (4..=64).contains(&target.len()),
+        }
+    }
+}
+
+/// [`Validator`] checks if Subresource Integrity (SRI) metadata.
+///
+/// SRI metadata is one or more whitespace-separated hashes, such as
+/// `sha384-<base64 digest>`. The algorithm must be `sha256`, `sha384`, or
+/// `sha512`, and the digest must be padded base64 of the algorithm's length.
+/// Options after a `?` are allowed.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::digest::*, seventy, Newtype};
+///
+/// #[seventy(validate(sri))]
+/// pub struct Integrity(String);
+///
+/// // Successfully constructed because the string is SRI metadata.
+/// assert!(Integrity::try_new(
+///     "sha384-lfymk72m+x+7sE88VHcbAR28pvrc2ZoHutAI84123LPcnuMBCdkVvkDryjLzv8SR"
+/// )
+/// .is_ok());
+///
+/// // Unsuccessfully constructed because the digest is SHA-256 length.
+/// assert!(Integrity::try_new("sha384-ZXsZ3t0WSpp82J1mEyWg++W/WjKqfKfOxBIUOG2dCeg=").is_err());
+///
+/// // Unsuccessfully constructed because MD5 is not allowed.
+/// assert!(Integrity::try_new("md5-VFahnWwRDqpvZXeM9KmEeA==").is_err());
+/// ```
+pub struct sri;
+
+impl<T> Validator<T> for sri
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let mut hashes = target.as_ref().split_ascii_whitespace().peekable();
+
+        hashes.peek().is_some()
+            && hashes.all(|hash| {
+                let hash = hash.split_once('?').map_or(hash, |(hash, _)| hash);
+
+                let Some((algorithm, digest)) = hash.split_once('-') else {
+                    return false;
+                };
+
+                let len = match algorithm {
+                    "sha256" => 32,
+                    "sha384" => 48,
+                    "sha512" => 64,
+                    _ => return false,
+                };
+
+                BASE64
+                    .decode(digest.as_bytes())
+                    .is_ok_and(|digest| digest.len() == len)
+            })
+    }
+}
+
+/// [`Validator`] checks if a base58 multihash.
+///
+/// A multihash is a varint hash function code, a varint digest length, and
+/// the digest, encoded with the Bitcoin base58 alphabet. This is the format of
+/// IPFS CIDv0, e.g. `Qm...` for SHA-256.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::digest::*, seventy, Newtype};
+///
+/// #[seventy(validate(multihash))]
+/// pub struct ContentId(String);
+///
+/// // Successfully constructed because the string is a multihash.
+/// assert!(ContentId::try_new("QmZrP4qbTtUtwReuj5E2GtHxgNAmbCwtLLhCpL8ZztCvsi").is_ok());
+///
+/// // Unsuccessfully constructed because the digest is shorter than its length.
+/// assert!(ContentId::try_new("6PLE11sS2ZWRufDDQ8JT7mhYeTzwxnJRKRrSLLHfk2BQX").is_err());
+/// ```
+pub struct multihash;
+
+impl<T> Validator<T> for multihash
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let Ok(decoded) = bs58_util::decode(target.as_ref()).into_vec() else {
+            return false;
+        };
+
+        let mut bytes = decoded.iter().copied();
+
+        let (Some(_code), Some(len)) = (read_varint(&mut bytes), read_varint(&mut bytes)) else {
+            return false;
+        };
+
+        len > 0 && bytes.len() as u64 == len
+    }
+}
+
+/// Reads an unsigned varint, up to 9 bytes.
+fn read_varint(bytes: &mut impl Iterator<Item = u8>) -> Option<u64> {
+    let mut value = 0;
+
+    for shift in (0..63).step_by(7) {
+        let byte = bytes.next()?;
+        value |= u64::from(byte & 0x7F) << shift;
+
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,271 @@
+//! Binary-to-text encoding built-ins.
+
+use data_encoding_util::{
+    Encoding as DataEncoding, BASE32, BASE64, BASE64URL, BASE64URL_NOPAD, BASE64_NOPAD,
+    HEXLOWER_PERMISSIVE,
+};
+
+use crate::{
+    builtins::string::OwnedString,
+    core::{Sanitizer, Validator},
+};
+
+/// Binary-to-text encoding, used by [`decoded_then`].
+pub trait Encoding {
+    /// Decodes the string, returning `None` if not valid.
+    fn decode(&self, target: &str) -> Option<Vec<u8>>;
+}
+
+/// [`Sanitizer`] converts hex to lowercase. [`Validator`] checks if valid hex.
+///
+/// Valid hex has an even number of hex digits, which can be either case.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::encoding::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(hex), validate(hex))]
+/// pub struct Checksum(String);
+///
+/// // Successfully constructed because the string is valid hex, and converted to lowercase.
+/// assert_eq!(
+///     Checksum::try_new("70AbCd").unwrap().into_inner(),
+///     "70abcd"
+/// );
+///
+/// // Unsuccessfully constructed because the strings are not valid hex.
+/// assert!(Checksum::try_new("707").is_err());
+/// assert!(Checksum::try_new("seventy").is_err());
+/// ```
+pub struct hex;
+
+impl Encoding for hex {
+    fn decode(&self, target: &str) -> Option<Vec<u8>> {
+        HEXLOWER_PERMISSIVE.decode(target.as_bytes()).ok()
+    }
+}
+
+impl<T> Sanitizer<T> for hex
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| target.make_ascii_lowercase());
+    }
+}
+
+impl<T> Validator<T> for hex
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.decode(target.as_ref()).is_some()
+    }
+}
+
+/// [`Sanitizer`] converts base64 to the variant's padding. [`Validator`]
+/// checks if valid base64 of the variant.
+///
+/// The `standard` alphabet uses `+` and `/`, and the `url_safe` alphabet uses
+/// `-` and `_`. The padded variants require `=` padding, and the unpadded
+/// variants forbid it.
+///
+/// The sanitizer adds or removes padding to match the variant, and leaves the
+/// string unchanged if it isn't valid base64 of the variant's alphabet.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::encoding::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(base64::standard), validate(base64::standard))]
+/// pub struct Avatar(String);
+///
+/// // Successfully constructed because the string is valid base64, and re-padded.
+/// assert_eq!(Avatar::try_new("c2V2ZW50eQ").unwrap().into_inner(), "c2V2ZW50eQ==");
+///
+/// // Unsuccessfully constructed because the string uses the URL-safe alphabet.
+/// assert!(Avatar::try_new("-_-_").is_err());
+///
+/// #[seventy(sanitize(base64::url_safe_unpadded), validate(base64::url_safe_unpadded))]
+/// pub struct SessionToken(String);
+///
+/// // Successfully constructed because the string is valid base64, and unpadded.
+/// assert_eq!(SessionToken::try_new("c2V2ZW50eQ==").unwrap().into_inner(), "c2V2ZW50eQ");
+///
+/// // Unsuccessfully constructed because the string uses the standard alphabet.
+/// assert!(SessionToken::try_new("+/+/").is_err());
+/// ```
+pub enum base64 {
+    standard,
+    standard_unpadded,
+    url_safe,
+    url_safe_unpadded,
+}
+
+impl base64 {
+    /// Returns the variant's encoding.
+    fn encoding(&self) -> DataEncoding {
+        match self {
+            Self::standard => BASE64,
+            Self::standard_unpadded => BASE64_NOPAD,
+            Self::url_safe => BASE64URL,
+            Self::url_safe_unpadded => BASE64URL_NOPAD,
+        }
+    }
+
+    /// Returns the variant's alphabet without padding.
+    fn unpadded_encoding(&self) -> DataEncoding {
+        match self {
+            Self::standard | Self::standard_unpadded => BASE64_NOPAD,
+            Self::url_safe | Self::url_safe_unpadded => BASE64URL_NOPAD,
+        }
+    }
+}
+
+impl Encoding for base64 {
+    fn decode(&self, target: &str) -> Option<Vec<u8>> {
+        self.encoding().decode(target.as_bytes()).ok()
+    }
+}
+
+impl<T> Sanitizer<T> for base64
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| {
+            let unpadded = target.trim_end_matches('=');
+
+            if let Ok(decoded) = self.unpadded_encoding().decode(unpadded.as_bytes()) {
+                *target = self.encoding().encode(&decoded);
+            }
+        });
+    }
+}
+
+impl<T> Validator<T> for base64
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.decode(target.as_ref()).is_some()
+    }
+}
+
+/// [`Validator`] checks if valid base32.
+///
+/// Uses the uppercase, padded alphabet from RFC 4648.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::encoding::*, seventy, Newtype};
+///
+/// #[seventy(validate(base32))]
+/// pub struct TotpSecret(String);
+///
+/// // Successfully constructed because the string is valid base32.
+/// assert!(TotpSecret::try_new("ONSXMZLOOR4Q====").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not valid base32.
+/// assert!(TotpSecret::try_new("ONSXMZLOOR4Q").is_err());
+/// assert!(TotpSecret::try_new("onsxmzloor4q====").is_err());
+/// ```
+pub struct base32;
+
+impl Encoding for base32 {
+    fn decode(&self, target: &str) -> Option<Vec<u8>> {
+        BASE32.decode(target.as_bytes()).ok()
+    }
+}
+
+impl<T> Validator<T> for base32
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.decode(target.as_ref()).is_some()
+    }
+}
+
+/// [`Validator`] checks if valid base58.
+///
+/// Uses the Bitcoin alphabet, which excludes `0`, `O`, `I`, and `l`.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::encoding::*, seventy, Newtype};
+///
+/// #[seventy(validate(base58))]
+/// pub struct ShortId(String);
+///
+/// // Successfully constructed because the string is valid base58.
+/// assert!(ShortId::try_new("2BGBNbt9SP").is_ok());
+///
+/// // Unsuccessfully constructed because the string contains '0'.
+/// assert!(ShortId::try_new("2BGBNbt0SP").is_err());
+/// ```
+pub struct base58;
+
+impl Encoding for base58 {
+    fn decode(&self, target: &str) -> Option<Vec<u8>> {
+        bs58_util::decode(target).into_vec().ok()
+    }
+}
+
+impl<T> Validator<T> for base58
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.decode(target.as_ref()).is_some()
+    }
+}
+
+/// [`Validator`] forwards the decoded byte length to inner validator.
+///
+/// Invalid if the string can't be decoded with the encoding.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{compare::*, encoding::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(decoded_then(hex, eq(32))))]
+/// pub struct Sha256(String);
+///
+/// // Successfully constructed because the hex decodes to 32 bytes.
+/// assert!(Sha256::try_new("7070707070707070707070707070707070707070707070707070707070707070").is_ok());
+///
+/// // Unsuccessfully constructed because the hex decodes to 4 bytes.
+/// assert!(Sha256::try_new("70707070").is_err());
+///
+/// #[seventy(validate(decoded_then(base64::url_safe_unpadded, ge(16))))]
+/// pub struct ApiKey(String);
+///
+/// // Successfully constructed because the base64 decodes to >= 16 bytes.
+/// assert!(ApiKey::try_new("c2V2ZW50eS1zZXZlbnR5LXNldmVudHk").is_ok());
+///
+/// // Unsuccessfully constructed because the base64 decodes to 7 bytes.
+/// assert!(ApiKey::try_new("c2V2ZW50eQ").is_err());
+/// ```
+pub struct decoded_then<E, V>(pub E, pub V);
+
+impl<T, E, V> Validator<T> for decoded_then<E, V>
+where
+    T: AsRef<str>,
+    E: Encoding,
+    V: Validator<usize>,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.0
+            .decode(target.as_ref())
+            .is_some_and(|decoded| self.1.validate(&decoded.len()))
+    }
+}
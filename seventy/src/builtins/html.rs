@@ -0,0 +1,176 @@
+//! HTML built-ins.
+
+use std::sync::LazyLock;
+
+use crate::{
+    builtins::string::OwnedString,
+    core::{Sanitizer, Validator},
+};
+
+pub use ammonia_util::Builder as HtmlPolicy;
+
+/// [`Sanitizer`] removes all HTML tags, keeping their text.
+///
+/// The contents of `script` and `style` elements are removed entirely. The
+/// remaining text is HTML-escaped, so it is safe to embed in HTML.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::html::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(strip_tags))]
+/// pub struct CommentBody(String);
+///
+/// // Removes the tags, keeping their text.
+/// assert_eq!(
+///     CommentBody::try_new("<p>Seventy is <b>cool</b>!</p><script>alert(70)</script>")
+///         .unwrap()
+///         .into_inner(),
+///     "Seventy is cool!"
+/// );
+/// ```
+pub struct strip_tags;
+
+impl<T> Sanitizer<T> for strip_tags
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        static POLICY: LazyLock<HtmlPolicy> = LazyLock::new(HtmlPolicy::empty);
+
+        target.with_string(|target| *target = POLICY.clean(target).to_string());
+    }
+}
+
+/// [`Sanitizer`] escapes HTML special characters.
+///
+/// The characters `&`, `<`, `>`, `"`, and `'` are replaced with their
+/// character references.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::html::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(escape))]
+/// pub struct CommentBody(String);
+///
+/// // Escapes the special characters.
+/// assert_eq!(
+///     CommentBody::try_new("<b>Tom & Jerry's</b>")
+///         .unwrap()
+///         .into_inner(),
+///     "&lt;b&gt;Tom &amp; Jerry&#39;s&lt;/b&gt;"
+/// );
+/// ```
+pub struct escape;
+
+impl<T> Sanitizer<T> for escape
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        const SPECIAL: [char; 5] = ['&', '<', '>', '"', '\''];
+
+        target.with_string(|target| {
+            if !target.contains(SPECIAL) {
+                return;
+            }
+
+            let mut escaped = String::with_capacity(target.len());
+
+            for c in target.chars() {
+                match c {
+                    '&' => escaped.push_str("&amp;"),
+                    '<' => escaped.push_str("&lt;"),
+                    '>' => escaped.push_str("&gt;"),
+                    '"' => escaped.push_str("&quot;"),
+                    '\'' => escaped.push_str("&#39;"),
+                    c => escaped.push(c),
+                }
+            }
+
+            *target = escaped;
+        });
+    }
+}
+
+/// [`Sanitizer`] removes HTML not allowed by the policy.
+///
+/// Uses [ammonia](https://docs.rs/ammonia), which parses the HTML and only
+/// keeps allow-listed tags, attributes, and URL schemes. The default policy is
+/// suitable for user-generated content.
+///
+/// Policies can be expensive to construct, so consider enabling the `shared`
+/// upgrade.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use seventy::{builtins::html::*, seventy, Newtype};
+///
+/// fn comment_policy() -> HtmlPolicy<'static> {
+///     let mut policy = HtmlPolicy::default();
+///     policy.tags(HashSet::from(["a", "b", "i"]));
+///     policy
+/// }
+///
+/// #[seventy(upgrades(shared), sanitize(clean(comment_policy())))]
+/// pub struct CommentBody(String);
+///
+/// // Removes the disallowed tags and attributes.
+/// assert_eq!(
+///     CommentBody::try_new(r#"<b onclick="steal()">Hi</b> <img src="x" onerror="steal()">"#)
+///         .unwrap()
+///         .into_inner(),
+///     "<b>Hi</b> "
+/// );
+/// ```
+pub struct clean<'a>(pub HtmlPolicy<'a>);
+
+impl<T> Sanitizer<T> for clean<'_>
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| *target = self.0.clean(target).to_string());
+    }
+}
+
+/// [`Validator`] checks if contains no HTML tags.
+///
+/// A `<` is the start of a tag if followed by an ASCII letter, `/`, `!`, or
+/// `?`, matching how HTML is tokenized. Other uses of `<` are allowed.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::html::*, seventy, Newtype};
+///
+/// #[seventy(validate(no_tags))]
+/// pub struct CommentBody(String);
+///
+/// // Successfully constructed because the strings contain no tags.
+/// assert!(CommentBody::try_new("Seventy is cool!").is_ok());
+/// assert!(CommentBody::try_new("1 < 70").is_ok());
+///
+/// // Unsuccessfully constructed because the strings contain tags.
+/// assert!(CommentBody::try_new("<b>Seventy</b>").is_err());
+/// assert!(CommentBody::try_new("Seventy<!-- is cool -->").is_err());
+/// ```
+pub struct no_tags;
+
+impl<T> Validator<T> for no_tags
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        !target.as_ref().as_bytes().windows(2).any(|window| {
+            window[0] == b'<'
+                && (window[1].is_ascii_alphabetic() || matches!(window[1], b'/' | b'!' | b'?'))
+        })
+    }
+}
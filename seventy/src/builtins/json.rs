@@ -0,0 +1,649 @@
+//! JSON built-ins.
+
+use crate::{
+    builtins::string::OwnedString,
+    core::{Sanitizer, Validator},
+};
+
+pub use serde_json_util::Value;
+
+/// [`Validator`] checks if well-formed JSON.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::json::*, seventy, Newtype};
+///
+/// #[seventy(validate(well_formed))]
+/// pub struct FlagConfig(String);
+///
+/// // Successfully constructed because the string is well-formed JSON.
+/// assert!(FlagConfig::try_new(r#"{"enabled": true, "rollout": 70}"#).is_ok());
+///
+/// // Unsuccessfully constructed because the string has a trailing comma.
+/// assert!(FlagConfig::try_new(r#"{"enabled": true,}"#).is_err());
+/// ```
+pub struct well_formed;
+
+impl<T> Validator<T> for well_formed
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        serde_json_util::from_str::<Value>(target.as_ref()).is_ok()
+    }
+}
+
+/// [`Validator`] forwards the parsed JSON [`Value`] to inner validator.
+///
+/// Invalid if the string is not well-formed JSON.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{json::*, predicate::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(parse_then(satisfies(Value::is_object))))]
+/// pub struct FlagConfig(String);
+///
+/// // Successfully constructed because the JSON is an object.
+/// assert!(FlagConfig::try_new(r#"{"enabled": true}"#).is_ok());
+///
+/// // Unsuccessfully constructed because the JSON is an array.
+/// assert!(FlagConfig::try_new("[true]").is_err());
+/// ```
+pub struct parse_then<V>(pub V);
+
+impl<T, V> Validator<T> for parse_then<V>
+where
+    T: AsRef<str>,
+    V: Validator<Value>,
+{
+    fn validate(&self, target: &T) -> bool {
+        serde_json_util::from_str(target.as_ref()).is_ok_and(|value| self.0.validate(&value))
+    }
+}
+
+/// [`Sanitizer`] removes insignificant whitespace from JSON.
+///
+/// The key order and number formatting are kept. Left unchanged if the string
+/// is not well-formed JSON. Use [`minify::sort_keys`] to also sort the keys.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::json::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(minify))]
+/// pub struct PayloadTemplate(String);
+///
+/// // Removes the whitespace outside of strings.
+/// assert_eq!(
+///     PayloadTemplate::try_new("{\n  \"text\": \"seven tea\",\n  \"cups\": [7, 0]\n}")
+///         .unwrap()
+///         .into_inner(),
+///     r#"{"text":"seven tea","cups":[7,0]}"#
+/// );
+/// ```
+pub struct minify;
+
+impl minify {
+    /// Also sorts object keys, recursively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::json::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(minify.sort_keys()))]
+    /// pub struct FlagConfig(String);
+    ///
+    /// // Removes the whitespace and sorts the keys.
+    /// assert_eq!(
+    ///     FlagConfig::try_new(r#"{"rollout": 70, "enabled": {"b": 1, "a": 2}}"#)
+    ///         .unwrap()
+    ///         .into_inner(),
+    ///     r#"{"enabled":{"a":2,"b":1},"rollout":70}"#
+    /// );
+    /// ```
+    pub fn sort_keys(self) -> minify_sort_keys {
+        minify_sort_keys
+    }
+}
+
+impl<T> Sanitizer<T> for minify
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| {
+            if !well_formed.validate(target) {
+                return;
+            }
+
+            let mut minified = String::with_capacity(target.len());
+            let mut in_string = false;
+            let mut escaped = false;
+
+            for c in target.chars() {
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if c == '\\' {
+                        escaped = true;
+                    } else if c == '"' {
+                        in_string = false;
+                    }
+                } else if c == '"' {
+                    in_string = true;
+                } else if matches!(c, ' ' | '\t' | '\n' | '\r') {
+                    continue;
+                }
+
+                minified.push(c);
+            }
+
+            *target = minified;
+        });
+    }
+}
+
+/// [`Sanitizer`] removes insignificant whitespace from JSON and sorts object
+/// keys.
+///
+/// Constructed with [`minify::sort_keys`]. Numbers are reformatted, e.g.
+/// `1e2` becomes `100.0`. Left unchanged if the string is not well-formed
+/// JSON.
+pub struct minify_sort_keys;
+
+impl<T> Sanitizer<T> for minify_sort_keys
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| {
+            if let Ok(value) = serde_json_util::from_str(target) {
+                *target = sort_keys(value).to_string();
+            }
+        });
+    }
+}
+
+/// Sorts object keys, recursively.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(array) => Value::Array(array.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+/// [`Validator`] checks if a JSON object.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::json::*, seventy, Newtype};
+///
+/// #[seventy(validate(is_object))]
+/// pub struct RequestBody(Value);
+///
+/// // Successfully constructed because the value is an object.
+/// assert!(RequestBody::try_new(r#"{"name": "Seventy"}"#.parse::<Value>().unwrap()).is_ok());
+///
+/// // Unsuccessfully constructed because the value is an array.
+/// assert!(RequestBody::try_new(r#"["Seventy"]"#.parse::<Value>().unwrap()).is_err());
+/// ```
+pub struct is_object;
+
+impl Validator<Value> for is_object {
+    fn validate(&self, target: &Value) -> bool {
+        target.is_object()
+    }
+}
+
+/// [`Validator`] checks if a JSON array.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::json::*, seventy, Newtype};
+///
+/// #[seventy(validate(is_array))]
+/// pub struct BatchBody(Value);
+///
+/// // Successfully constructed because the value is an array.
+/// assert!(BatchBody::try_new(r#"[7, 0]"#.parse::<Value>().unwrap()).is_ok());
+///
+/// // Unsuccessfully constructed because the value is a number.
+/// assert!(BatchBody::try_new("70".parse::<Value>().unwrap()).is_err());
+/// ```
+pub struct is_array;
+
+impl Validator<Value> for is_array {
+    fn validate(&self, target: &Value) -> bool {
+        target.is_array()
+    }
+}
+
+/// [`Validator`] checks if a JSON string.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::json::*, seventy, Newtype};
+///
+/// #[seventy(validate(is_string))]
+/// pub struct Label(Value);
+///
+/// // Successfully constructed because the value is a string.
+/// assert!(Label::try_new(Value::from("Seventy")).is_ok());
+///
+/// // Unsuccessfully constructed because the value is a number.
+/// assert!(Label::try_new(Value::from(70)).is_err());
+/// ```
+pub struct is_string;
+
+impl Validator<Value> for is_string {
+    fn validate(&self, target: &Value) -> bool {
+        target.is_string()
+    }
+}
+
+/// [`Sanitizer`] and [`Validator`] forwards the object's field to inner
+/// sanitizer or validator.
+///
+/// For [`Sanitizer`], if not an object or the field is missing, the inner
+/// sanitizer is skipped.
+///
+/// For [`Validator`], if not an object or the field is missing, the
+/// validation is invalid. See [`optional_field`] if the field can be missing.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{compare::*, json::*, string::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(
+///     sanitize(field("name", as_str(trim))),
+///     validate(field("name", as_str(not_empty)), field("age", as_u64(within(0..150))))
+/// )]
+/// pub struct Person(Value);
+///
+/// // Trims the name.
+/// // Successfully constructed because the name is not empty, and the age is within 0..150.
+/// assert_eq!(
+///     Person::try_new(r#"{"name": " Seventy ", "age": 70}"#.parse::<Value>().unwrap())
+///         .unwrap()
+///         .into_inner()["name"],
+///     "Seventy"
+/// );
+///
+/// // Unsuccessfully constructed because the age is not within 0..150.
+/// assert!(Person::try_new(r#"{"name": "Seventy", "age": 700}"#.parse::<Value>().unwrap()).is_err());
+///
+/// // Unsuccessfully constructed because the age is missing.
+/// assert!(Person::try_new(r#"{"name": "Seventy"}"#.parse::<Value>().unwrap()).is_err());
+/// ```
+pub struct field<K, SV>(pub K, pub SV);
+
+impl<K, S> Sanitizer<Value> for field<K, S>
+where
+    K: AsRef<str>,
+    S: Sanitizer<Value>,
+{
+    fn sanitize(&self, target: &mut Value) {
+        if let Some(value) = target.get_mut(self.0.as_ref()) {
+            self.1.sanitize(value);
+        }
+    }
+}
+
+impl<K, V> Validator<Value> for field<K, V>
+where
+    K: AsRef<str>,
+    V: Validator<Value>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        target
+            .get(self.0.as_ref())
+            .is_some_and(|value| self.1.validate(value))
+    }
+}
+
+/// [`Sanitizer`] and [`Validator`] forwards the object's field, if present, to
+/// inner sanitizer or validator.
+///
+/// For [`Sanitizer`], if not an object or the field is missing, the inner
+/// sanitizer is skipped.
+///
+/// For [`Validator`], if not an object the validation is invalid. If the field
+/// is missing the inner validator is skipped and the validation is valid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{json::*, string::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(optional_field("nickname", as_str(alphabetic))))]
+/// pub struct Person(Value);
+///
+/// // Successfully constructed because the nickname is alphabetic.
+/// assert!(Person::try_new(r#"{"nickname": "Seventy"}"#.parse::<Value>().unwrap()).is_ok());
+///
+/// // Successfully constructed because the nickname is missing.
+/// assert!(Person::try_new(r#"{}"#.parse::<Value>().unwrap()).is_ok());
+///
+/// // Unsuccessfully constructed because the nickname is not alphabetic.
+/// assert!(Person::try_new(r#"{"nickname": "Seventy70"}"#.parse::<Value>().unwrap()).is_err());
+/// ```
+pub struct optional_field<K, SV>(pub K, pub SV);
+
+impl<K, S> Sanitizer<Value> for optional_field<K, S>
+where
+    K: AsRef<str>,
+    S: Sanitizer<Value>,
+{
+    fn sanitize(&self, target: &mut Value) {
+        if let Some(value) = target.get_mut(self.0.as_ref()) {
+            self.1.sanitize(value);
+        }
+    }
+}
+
+impl<K, V> Validator<Value> for optional_field<K, V>
+where
+    K: AsRef<str>,
+    V: Validator<Value>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        target.as_object().is_some_and(|object| {
+            object
+                .get(self.0.as_ref())
+                .is_none_or(|value| self.1.validate(value))
+        })
+    }
+}
+
+/// [`Sanitizer`] and [`Validator`] forwards the array's item at the index to
+/// inner sanitizer or validator.
+///
+/// For [`Sanitizer`], if not an array or the index is out of bounds, the
+/// inner sanitizer is skipped.
+///
+/// For [`Validator`], if not an array or the index is out of bounds, the
+/// validation is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{json::*, string::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(index(0, as_str(starts_with("v")))))]
+/// pub struct VersionedBatch(Value);
+///
+/// // Successfully constructed because the first item starts with "v".
+/// assert!(VersionedBatch::try_new(r#"["v70", 7, 0]"#.parse::<Value>().unwrap()).is_ok());
+///
+/// // Unsuccessfully constructed because the first item doesn't start with "v".
+/// assert!(VersionedBatch::try_new(r#"["70", 7, 0]"#.parse::<Value>().unwrap()).is_err());
+///
+/// // Unsuccessfully constructed because there is no first item.
+/// assert!(VersionedBatch::try_new(r#"[]"#.parse::<Value>().unwrap()).is_err());
+/// ```
+pub struct index<SV>(pub usize, pub SV);
+
+impl<S> Sanitizer<Value> for index<S>
+where
+    S: Sanitizer<Value>,
+{
+    fn sanitize(&self, target: &mut Value) {
+        if let Some(value) = target.get_mut(self.0) {
+            self.1.sanitize(value);
+        }
+    }
+}
+
+impl<V> Validator<Value> for index<V>
+where
+    V: Validator<Value>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        target
+            .get(self.0)
+            .is_some_and(|value| self.1.validate(value))
+    }
+}
+
+/// [`Sanitizer`] and [`Validator`] forwards each of the array's items to inner
+/// sanitizer or validator.
+///
+/// For [`Sanitizer`], if not an array the inner sanitizer is skipped.
+///
+/// For [`Validator`], if not an array the validation is invalid. Otherwise,
+/// valid if all items are valid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{json::*, string::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(sanitize(each_item(as_str(lowercase))), validate(each_item(is_string)))]
+/// pub struct Tags(Value);
+///
+/// // Converts each tag to lowercase.
+/// // Successfully constructed because each item is a string.
+/// assert_eq!(
+///     Tags::try_new(r#"["Seven", "TEA"]"#.parse::<Value>().unwrap())
+///         .unwrap()
+///         .into_inner(),
+///     r#"["seven", "tea"]"#.parse::<Value>().unwrap()
+/// );
+///
+/// // Unsuccessfully constructed because an item is a number.
+/// assert!(Tags::try_new(r#"["seven", 70]"#.parse::<Value>().unwrap()).is_err());
+/// ```
+pub struct each_item<SV>(pub SV);
+
+impl<S> Sanitizer<Value> for each_item<S>
+where
+    S: Sanitizer<Value>,
+{
+    fn sanitize(&self, target: &mut Value) {
+        if let Some(items) = target.as_array_mut() {
+            for item in items {
+                self.0.sanitize(item);
+            }
+        }
+    }
+}
+
+impl<V> Validator<Value> for each_item<V>
+where
+    V: Validator<Value>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        target
+            .as_array()
+            .is_some_and(|items| items.iter().all(|item| self.0.validate(item)))
+    }
+}
+
+/// [`Sanitizer`] removes fields not in the list. [`Validator`] checks if the
+/// object has no fields not in the list.
+///
+/// For [`Sanitizer`], if not an object nothing is removed.
+///
+/// For [`Validator`], if not an object the validation is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::json::*, seventy, Newtype};
+///
+/// #[seventy(validate(no_extra_fields(["name", "age"])))]
+/// pub struct Person(Value);
+///
+/// // Successfully constructed because there are only known fields.
+/// assert!(Person::try_new(r#"{"name": "Seventy"}"#.parse::<Value>().unwrap()).is_ok());
+///
+/// // Unsuccessfully constructed because "admin" is an extra field.
+/// assert!(Person::try_new(r#"{"name": "Seventy", "admin": true}"#.parse::<Value>().unwrap()).is_err());
+///
+/// #[seventy(sanitize(no_extra_fields(["name", "age"])))]
+/// pub struct LenientPerson(Value);
+///
+/// // Removes the extra field.
+/// assert_eq!(
+///     LenientPerson::try_new(r#"{"name": "Seventy", "admin": true}"#.parse::<Value>().unwrap())
+///         .unwrap()
+///         .into_inner(),
+///     r#"{"name": "Seventy"}"#.parse::<Value>().unwrap()
+/// );
+/// ```
+pub struct no_extra_fields<C>(pub C);
+
+impl<K, const N: usize> Sanitizer<Value> for no_extra_fields<[K; N]>
+where
+    K: AsRef<str>,
+{
+    fn sanitize(&self, target: &mut Value) {
+        if let Some(object) = target.as_object_mut() {
+            object.retain(|key, _| self.0.iter().any(|allowed| allowed.as_ref() == key));
+        }
+    }
+}
+
+impl<K, const N: usize> Validator<Value> for no_extra_fields<[K; N]>
+where
+    K: AsRef<str>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        target.as_object().is_some_and(|object| {
+            object
+                .keys()
+                .all(|key| self.0.iter().any(|allowed| allowed.as_ref() == key))
+        })
+    }
+}
+
+/// [`Sanitizer`] and [`Validator`] forwards the string to inner sanitizer or
+/// validator.
+///
+/// For [`Sanitizer`], if not a string the inner sanitizer is skipped.
+///
+/// For [`Validator`], if not a string the validation is invalid.
+///
+/// See [`field`] for an example.
+pub struct as_str<SV>(pub SV);
+
+impl<S> Sanitizer<Value> for as_str<S>
+where
+    S: Sanitizer<String>,
+{
+    fn sanitize(&self, target: &mut Value) {
+        if let Value::String(string) = target {
+            self.0.sanitize(string);
+        }
+    }
+}
+
+impl<V> Validator<Value> for as_str<V>
+where
+    V: Validator<String>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        matches!(target, Value::String(string) if self.0.validate(string))
+    }
+}
+
+/// [`Validator`] forwards the boolean to inner validator.
+///
+/// If not a boolean, the validation is invalid.
+pub struct as_bool<V>(pub V);
+
+impl<V> Validator<Value> for as_bool<V>
+where
+    V: Validator<bool>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        target
+            .as_bool()
+            .is_some_and(|boolean| self.0.validate(&boolean))
+    }
+}
+
+/// [`Validator`] forwards the number as a [`u64`] to inner validator.
+///
+/// If not a number representable as a [`u64`], the validation is invalid.
+///
+/// See [`field`] for an example.
+pub struct as_u64<V>(pub V);
+
+impl<V> Validator<Value> for as_u64<V>
+where
+    V: Validator<u64>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        target
+            .as_u64()
+            .is_some_and(|number| self.0.validate(&number))
+    }
+}
+
+/// [`Validator`] forwards the number as an [`i64`] to inner validator.
+///
+/// If not a number representable as an [`i64`], the validation is invalid.
+pub struct as_i64<V>(pub V);
+
+impl<V> Validator<Value> for as_i64<V>
+where
+    V: Validator<i64>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        target
+            .as_i64()
+            .is_some_and(|number| self.0.validate(&number))
+    }
+}
+
+/// [`Validator`] forwards the number as an [`f64`] to inner validator.
+///
+/// If not a number, the validation is invalid.
+pub struct as_f64<V>(pub V);
+
+impl<V> Validator<Value> for as_f64<V>
+where
+    V: Validator<f64>,
+{
+    fn validate(&self, target: &Value) -> bool {
+        target
+            .as_f64()
+            .is_some_and(|number| self.0.validate(&number))
+    }
+}
@@ -0,0 +1,14 @@
+//! Leetspeak helpers shared by built-ins.
+
+/// Reverts common leetspeak substitutions and converts to lowercase.
+pub(super) fn unleet(c: char) -> char {
+    match c {
+        '4' | '@' => 'a',
+        '3' => 'e',
+        '1' | '!' => 'i',
+        '0' => 'o',
+        '5' | '$' => 's',
+        '7' => 't',
+        c => c.to_ascii_lowercase(),
+    }
+}
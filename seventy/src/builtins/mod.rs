@@ -24,12 +24,31 @@ pub mod collection;
 pub mod compare;
 #[cfg(feature = "credit-card")]
 pub mod credit_card;
+#[cfg(feature = "digest")]
+pub mod digest;
 #[cfg(feature = "email")]
 pub mod email;
+#[cfg(feature = "encoding")]
+pub mod encoding;
 pub mod float;
+#[cfg(feature = "html")]
+pub mod html;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "jwt")]
+pub mod jwt;
+mod leet;
+pub mod net;
 pub mod operator;
 pub mod option;
+pub mod password;
 pub mod predicate;
+#[cfg(feature = "semver")]
+pub mod semver;
 pub mod string;
+#[cfg(feature = "unicode-security")]
+pub mod unicode_security;
 #[cfg(feature = "url")]
 pub mod url;
+#[cfg(feature = "uuid")]
+pub mod uuid;
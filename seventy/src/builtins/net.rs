@@ -0,0 +1,532 @@
+//! Network address built-ins.
+
+use std::{
+    borrow::Cow,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+use crate::{
+    builtins::string::OwnedString,
+    core::{Sanitizer, Validator},
+};
+
+/// Value that can be read as an [`IpAddr`].
+///
+/// Implemented for strings, which are parsed, and for [`IpAddr`],
+/// [`Ipv4Addr`], and [`Ipv6Addr`].
+pub trait AsIpAddr {
+    /// Reads as an [`IpAddr`], returning `None` if not an IP address.
+    fn as_ip_addr(&self) -> Option<IpAddr>;
+}
+
+impl AsIpAddr for IpAddr {
+    fn as_ip_addr(&self) -> Option<IpAddr> {
+        Some(*self)
+    }
+}
+
+impl AsIpAddr for Ipv4Addr {
+    fn as_ip_addr(&self) -> Option<IpAddr> {
+        Some(IpAddr::V4(*self))
+    }
+}
+
+impl AsIpAddr for Ipv6Addr {
+    fn as_ip_addr(&self) -> Option<IpAddr> {
+        Some(IpAddr::V6(*self))
+    }
+}
+
+impl AsIpAddr for str {
+    fn as_ip_addr(&self) -> Option<IpAddr> {
+        self.parse().ok()
+    }
+}
+
+impl AsIpAddr for &str {
+    fn as_ip_addr(&self) -> Option<IpAddr> {
+        str::as_ip_addr(self)
+    }
+}
+
+impl AsIpAddr for String {
+    fn as_ip_addr(&self) -> Option<IpAddr> {
+        str::as_ip_addr(self)
+    }
+}
+
+impl AsIpAddr for Box<str> {
+    fn as_ip_addr(&self) -> Option<IpAddr> {
+        str::as_ip_addr(self)
+    }
+}
+
+impl AsIpAddr for Cow<'_, str> {
+    fn as_ip_addr(&self) -> Option<IpAddr> {
+        str::as_ip_addr(self)
+    }
+}
+
+/// [`Validator`] checks if an IPv4 address.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(ipv4))]
+/// pub struct BindAddress(String);
+///
+/// // Successfully constructed because the string is an IPv4 address.
+/// assert!(BindAddress::try_new("192.168.7.70").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not IPv4 addresses.
+/// assert!(BindAddress::try_new("::1").is_err());
+/// assert!(BindAddress::try_new("192.168.7.700").is_err());
+/// ```
+pub struct ipv4;
+
+impl<T> Validator<T> for ipv4
+where
+    T: AsIpAddr,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ip_addr().is_some_and(|address| address.is_ipv4())
+    }
+}
+
+/// [`Validator`] checks if an IPv6 address.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(ipv6))]
+/// pub struct BindAddress(String);
+///
+/// // Successfully constructed because the string is an IPv6 address.
+/// assert!(BindAddress::try_new("2001:db8::70").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not IPv6 addresses.
+/// assert!(BindAddress::try_new("192.168.7.70").is_err());
+/// assert!(BindAddress::try_new("2001:db8:::70").is_err());
+/// ```
+pub struct ipv6;
+
+impl<T> Validator<T> for ipv6
+where
+    T: AsIpAddr,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ip_addr().is_some_and(|address| address.is_ipv6())
+    }
+}
+
+/// [`Validator`] checks if an IPv4 or IPv6 address.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(ip))]
+/// pub struct BindAddress(String);
+///
+/// // Successfully constructed because the strings are IP addresses.
+/// assert!(BindAddress::try_new("192.168.7.70").is_ok());
+/// assert!(BindAddress::try_new("2001:db8::70").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not an IP address.
+/// assert!(BindAddress::try_new("localhost").is_err());
+/// ```
+pub struct ip;
+
+impl<T> Validator<T> for ip
+where
+    T: AsIpAddr,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ip_addr().is_some()
+    }
+}
+
+/// [`Validator`] checks if CIDR notation.
+///
+/// CIDR notation is an IP address and a prefix length, such as `10.0.0.0/8`.
+/// The prefix length is at most 32 for IPv4 and 128 for IPv6. Bits after the
+/// prefix are allowed, e.g. `10.0.0.70/8`.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(cidr))]
+/// pub struct AllowedNetwork(String);
+///
+/// // Successfully constructed because the strings are CIDR notation.
+/// assert!(AllowedNetwork::try_new("10.0.0.0/8").is_ok());
+/// assert!(AllowedNetwork::try_new("2001:db8::/32").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not CIDR notation.
+/// assert!(AllowedNetwork::try_new("10.0.0.0").is_err());
+/// assert!(AllowedNetwork::try_new("10.0.0.0/70").is_err());
+/// ```
+pub struct cidr;
+
+impl<T> Validator<T> for cidr
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        parse_cidr(target.as_ref()).is_some()
+    }
+}
+
+/// [`Validator`] checks if a socket address.
+///
+/// A socket address is an IP address and a port, such as `127.0.0.1:7070` or
+/// `[::1]:7070`.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(socket_addr))]
+/// pub struct BindAddress(String);
+///
+/// // Successfully constructed because the strings are socket addresses.
+/// assert!(BindAddress::try_new("127.0.0.1:7070").is_ok());
+/// assert!(BindAddress::try_new("[::1]:7070").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not socket addresses.
+/// assert!(BindAddress::try_new("127.0.0.1").is_err());
+/// assert!(BindAddress::try_new("localhost:7070").is_err());
+/// ```
+pub struct socket_addr;
+
+impl<T> Validator<T> for socket_addr
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().parse::<SocketAddr>().is_ok()
+    }
+}
+
+/// [`Validator`] checks if a port.
+///
+/// A port is a decimal number from 0 to 65535, without a sign.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(port))]
+/// pub struct Port(String);
+///
+/// // Successfully constructed because the string is a port.
+/// assert!(Port::try_new("7070").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not ports.
+/// assert!(Port::try_new("70700").is_err());
+/// assert!(Port::try_new("+7070").is_err());
+/// ```
+pub struct port;
+
+impl<T> Validator<T> for port
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+
+        target.bytes().all(|byte| byte.is_ascii_digit()) && target.parse::<u16>().is_ok()
+    }
+}
+
+/// [`Validator`] forwards the IP address to inner validator.
+///
+/// If not an IP address, the validation is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(ip_then(in_cidr("10.0.0.0/8"))))]
+/// pub struct InternalAddress(String);
+///
+/// // Successfully constructed because the address is in 10.0.0.0/8.
+/// assert!(InternalAddress::try_new("10.7.0.70").is_ok());
+///
+/// // Unsuccessfully constructed because the address is not in 10.0.0.0/8.
+/// assert!(InternalAddress::try_new("192.168.7.70").is_err());
+/// ```
+pub struct ip_then<V>(pub V);
+
+impl<T, V> Validator<T> for ip_then<V>
+where
+    T: AsIpAddr,
+    V: Validator<IpAddr>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target
+            .as_ip_addr()
+            .is_some_and(|address| self.0.validate(&address))
+    }
+}
+
+/// [`Validator`] checks if a private IP address.
+///
+/// Private IPv4 addresses are in `10.0.0.0/8`, `172.16.0.0/12`, and
+/// `192.168.0.0/16`. Private IPv6 addresses are unique local addresses, in
+/// `fc00::/7`.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::IpAddr;
+///
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(private))]
+/// pub struct InternalAddress(IpAddr);
+///
+/// // Successfully constructed because the addresses are private.
+/// assert!(InternalAddress::try_new("192.168.7.70".parse::<IpAddr>().unwrap()).is_ok());
+/// assert!(InternalAddress::try_new("fd00::70".parse::<IpAddr>().unwrap()).is_ok());
+///
+/// // Unsuccessfully constructed because the address is public.
+/// assert!(InternalAddress::try_new("8.8.8.8".parse::<IpAddr>().unwrap()).is_err());
+/// ```
+pub struct private;
+
+impl Validator<IpAddr> for private {
+    fn validate(&self, target: &IpAddr) -> bool {
+        match target {
+            IpAddr::V4(address) => address.is_private(),
+            IpAddr::V6(address) => address.segments()[0] & 0xFE00 == 0xFC00,
+        }
+    }
+}
+
+/// [`Validator`] checks if a loopback IP address.
+///
+/// Loopback IPv4 addresses are in `127.0.0.0/8`, and the loopback IPv6
+/// address is `::1`.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(ip_then(loopback)))]
+/// pub struct LocalAddress(String);
+///
+/// // Successfully constructed because the addresses are loopback.
+/// assert!(LocalAddress::try_new("127.0.0.70").is_ok());
+/// assert!(LocalAddress::try_new("::1").is_ok());
+///
+/// // Unsuccessfully constructed because the address is not loopback.
+/// assert!(LocalAddress::try_new("192.168.7.70").is_err());
+/// ```
+pub struct loopback;
+
+impl Validator<IpAddr> for loopback {
+    fn validate(&self, target: &IpAddr) -> bool {
+        target.is_loopback()
+    }
+}
+
+/// [`Validator`] checks if a globally reachable IP address.
+///
+/// Not globally reachable are unspecified, private, shared (`100.64.0.0/10`),
+/// loopback, link-local, documentation, benchmarking (`198.18.0.0/15`),
+/// reserved (`240.0.0.0/4`), and broadcast addresses, as well as the IETF
+/// protocol assignments (`192.0.0.0/24`) and IPv4-mapped IPv6 addresses.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(ip_then(global)))]
+/// pub struct PublicAddress(String);
+///
+/// // Successfully constructed because the addresses are globally reachable.
+/// assert!(PublicAddress::try_new("8.8.8.8").is_ok());
+/// assert!(PublicAddress::try_new("2606:4700::1111").is_ok());
+///
+/// // Unsuccessfully constructed because the addresses are not globally reachable.
+/// assert!(PublicAddress::try_new("10.7.0.70").is_err());
+/// assert!(PublicAddress::try_new("100.64.7.70").is_err());
+/// assert!(PublicAddress::try_new("2001:db8::70").is_err());
+/// ```
+pub struct global;
+
+impl Validator<IpAddr> for global {
+    fn validate(&self, target: &IpAddr) -> bool {
+        match target {
+            IpAddr::V4(address) => {
+                let [a, b, c, _] = address.octets();
+
+                !(a == 0
+                    || address.is_private()
+                    || (a == 100 && b & 0xC0 == 64)
+                    || address.is_loopback()
+                    || address.is_link_local()
+                    || (a == 192 && b == 0 && c == 0)
+                    || address.is_documentation()
+                    || (a == 198 && b & 0xFE == 18)
+                    || a >= 240)
+            }
+            IpAddr::V6(address) => {
+                let segments = address.segments();
+
+                !(address.is_unspecified()
+                    || address.is_loopback()
+                    || address.to_ipv4_mapped().is_some()
+                    || (segments[0] == 0x2001 && segments[1] == 0xDB8)
+                    || segments[0] & 0xFE00 == 0xFC00
+                    || segments[0] & 0xFFC0 == 0xFE80)
+            }
+        }
+    }
+}
+
+/// [`Validator`] checks if a multicast IP address.
+///
+/// Multicast IPv4 addresses are in `224.0.0.0/4`, and multicast IPv6
+/// addresses are in `ff00::/8`.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(ip_then(multicast)))]
+/// pub struct MulticastGroup(String);
+///
+/// // Successfully constructed because the addresses are multicast.
+/// assert!(MulticastGroup::try_new("239.7.0.70").is_ok());
+/// assert!(MulticastGroup::try_new("ff02::fb").is_ok());
+///
+/// // Unsuccessfully constructed because the address is not multicast.
+/// assert!(MulticastGroup::try_new("10.7.0.70").is_err());
+/// ```
+pub struct multicast;
+
+impl Validator<IpAddr> for multicast {
+    fn validate(&self, target: &IpAddr) -> bool {
+        target.is_multicast()
+    }
+}
+
+/// [`Validator`] checks if an IP address in the CIDR block.
+///
+/// An IPv4 address is never in an IPv6 block, and vice versa.
+///
+/// # Panics
+///
+/// Panics if the string is not CIDR notation (see [`cidr`]).
+///
+/// # Examples
+///
+/// ```
+/// use std::net::IpAddr;
+///
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(validate(in_cidr("10.0.0.0/8")))]
+/// pub struct InternalAddress(IpAddr);
+///
+/// // Successfully constructed because the address is in 10.0.0.0/8.
+/// assert!(InternalAddress::try_new("10.7.0.70".parse::<IpAddr>().unwrap()).is_ok());
+///
+/// // Unsuccessfully constructed because the addresses are not in 10.0.0.0/8.
+/// assert!(InternalAddress::try_new("11.7.0.70".parse::<IpAddr>().unwrap()).is_err());
+/// assert!(InternalAddress::try_new("::ffff:10.7.0.70".parse::<IpAddr>().unwrap()).is_err());
+/// ```
+pub fn in_cidr(block: &str) -> _in_cidr {
+    let (network, prefix) = parse_cidr(block).expect("invalid CIDR notation");
+    _in_cidr(network, prefix)
+}
+
+#[doc(hidden)]
+pub struct _in_cidr(IpAddr, u8);
+
+impl Validator<IpAddr> for _in_cidr {
+    fn validate(&self, target: &IpAddr) -> bool {
+        let mask = |bits: u32, prefix: u8| u128::MAX.checked_shl(bits - u32::from(prefix));
+
+        match (self.0, target) {
+            (IpAddr::V4(network), IpAddr::V4(target)) => {
+                let mask = mask(32, self.1).unwrap_or(0) as u32;
+                u32::from(network) & mask == u32::from(*target) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(target)) => {
+                let mask = mask(128, self.1).unwrap_or(0);
+                u128::from(network) & mask == u128::from(*target) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Parses CIDR notation into the network address and prefix length.
+fn parse_cidr(target: &str) -> Option<(IpAddr, u8)> {
+    let (network, prefix) = target.split_once('/')?;
+
+    let network = network.parse::<IpAddr>().ok()?;
+
+    if !prefix.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let prefix = prefix.parse::<u8>().ok()?;
+    let max = if network.is_ipv4() { 32 } else { 128 };
+
+    (prefix <= max).then_some((network, prefix))
+}
+
+/// [`Sanitizer`] converts an IPv6 address to its canonical text.
+///
+/// The canonical text follows RFC 5952: lowercase, leading zeros removed, and
+/// the longest run of zero groups compressed to `::`. Left unchanged if not an
+/// IPv6 address.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::net::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(canonicalize_ipv6), validate(ip))]
+/// pub struct AllowedAddress(String);
+///
+/// // Converts to the canonical text.
+/// assert_eq!(
+///     AllowedAddress::try_new("2001:0DB8:0000:0000:0000:0000:0000:0070").unwrap().into_inner(),
+///     "2001:db8::70"
+/// );
+///
+/// // IPv4 addresses are unchanged.
+/// assert_eq!(AllowedAddress::try_new("10.7.0.70").unwrap().into_inner(), "10.7.0.70");
+/// ```
+pub struct canonicalize_ipv6;
+
+impl<T> Sanitizer<T> for canonicalize_ipv6
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| {
+            if let Ok(address) = target.parse::<Ipv6Addr>() {
+                *target = address.to_string();
+            }
+        });
+    }
+}
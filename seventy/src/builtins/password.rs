@@ -0,0 +1,560 @@
+//! Password built-ins.
+
+use crate::{builtins::leet::unleet, core::Validator};
+
+/// Password strength score.
+///
+/// Each score corresponds to an estimated number of guesses needed to crack
+/// the password.
+///
+/// | Score        | Guesses       |
+/// | ------------ | ------------- |
+/// | `VeryWeak`   | < 10^3        |
+/// | `Weak`       | < 10^6        |
+/// | `Fair`       | < 10^8        |
+/// | `Strong`     | < 10^10       |
+/// | `VeryStrong` | >= 10^10      |
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Score {
+    VeryWeak,
+    Weak,
+    Fair,
+    Strong,
+    VeryStrong,
+}
+
+impl Score {
+    /// Estimates the strength of the password.
+    ///
+    /// The estimate is inspired by [zxcvbn](https://github.com/dropbox/zxcvbn).
+    /// The password is split into the fewest guessable segments, which are
+    /// common passwords (including leetspeak and capitalized variants),
+    /// repeated characters, character sequences, and keyboard rows. Other
+    /// characters are guessed by brute force.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::builtins::password::Score;
+    ///
+    /// assert_eq!(Score::estimate("password"), Score::VeryWeak);
+    /// assert_eq!(Score::estimate("Michael1970"), Score::Weak);
+    /// assert_eq!(Score::estimate("correct horse battery staple"), Score::VeryStrong);
+    /// ```
+    pub fn estimate(password: &str) -> Self {
+        match estimate_guesses_log10(password) {
+            guesses if guesses < 3.0 => Self::VeryWeak,
+            guesses if guesses < 6.0 => Self::Weak,
+            guesses if guesses < 8.0 => Self::Fair,
+            guesses if guesses < 10.0 => Self::Strong,
+            _ => Self::VeryStrong,
+        }
+    }
+}
+
+/// [`Validator`] forwards the estimated strength [`Score`] to inner validator.
+///
+/// See [`Score::estimate`] for how the strength is estimated.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{compare::*, password::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(strength(ge(Score::Strong))))]
+/// pub struct Password(String);
+///
+/// // Successfully constructed because the password is strong.
+/// assert!(Password::try_new("Seven-Tea-Cups-70").is_ok());
+///
+/// // Unsuccessfully constructed because the passwords are not strong.
+/// assert!(Password::try_new("p455w0rd70!").is_err());
+/// assert!(Password::try_new("qwerty123456").is_err());
+/// assert!(Password::try_new("aaaaaaaaaaaa").is_err());
+/// ```
+pub struct strength<V>(pub V);
+
+impl<T, V> Validator<T> for strength<V>
+where
+    T: AsRef<str>,
+    V: Validator<Score>,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.0.validate(&Score::estimate(target.as_ref()))
+    }
+}
+
+/// [`Validator`] checks if contains a lowercase character.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::password::*, seventy, Newtype};
+///
+/// #[seventy(validate(has_lowercase))]
+/// pub struct Password(String);
+///
+/// // Successfully constructed because 'e' is lowercase.
+/// assert!(Password::try_new("SEVENTy").is_ok());
+///
+/// // Unsuccessfully constructed because no character is lowercase.
+/// assert!(Password::try_new("SEVENTY").is_err());
+/// ```
+pub struct has_lowercase;
+
+impl<T> Validator<T> for has_lowercase
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().any(char::is_lowercase)
+    }
+}
+
+/// [`Validator`] checks if contains an uppercase character.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::password::*, seventy, Newtype};
+///
+/// #[seventy(validate(has_uppercase))]
+/// pub struct Password(String);
+///
+/// // Successfully constructed because 'S' is uppercase.
+/// assert!(Password::try_new("Seventy").is_ok());
+///
+/// // Unsuccessfully constructed because no character is uppercase.
+/// assert!(Password::try_new("seventy").is_err());
+/// ```
+pub struct has_uppercase;
+
+impl<T> Validator<T> for has_uppercase
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().any(char::is_uppercase)
+    }
+}
+
+/// [`Validator`] checks if contains a digit.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::password::*, seventy, Newtype};
+///
+/// #[seventy(validate(has_digit))]
+/// pub struct Password(String);
+///
+/// // Successfully constructed because '7' is a digit.
+/// assert!(Password::try_new("seventy7").is_ok());
+///
+/// // Unsuccessfully constructed because no character is a digit.
+/// assert!(Password::try_new("seventy").is_err());
+/// ```
+pub struct has_digit;
+
+impl<T> Validator<T> for has_digit
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().any(|c| c.is_ascii_digit())
+    }
+}
+
+/// [`Validator`] checks if contains a symbol.
+///
+/// A symbol is any character that is not alphanumeric or whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::password::*, seventy, Newtype};
+///
+/// #[seventy(validate(has_symbol))]
+/// pub struct Password(String);
+///
+/// // Successfully constructed because '!' is a symbol.
+/// assert!(Password::try_new("seventy!").is_ok());
+///
+/// // Unsuccessfully constructed because no character is a symbol.
+/// assert!(Password::try_new("seventy 70").is_err());
+/// ```
+pub struct has_symbol;
+
+impl<T> Validator<T> for has_symbol
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().any(is_symbol)
+    }
+}
+
+/// [`Validator`] checks if contains at least the given number of character
+/// classes.
+///
+/// The character classes are lowercase, uppercase, digits, and symbols.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::password::*, seventy, Newtype};
+///
+/// #[seventy(validate(min_classes(3)))]
+/// pub struct Password(String);
+///
+/// // Successfully constructed because there are 3 character classes.
+/// assert!(Password::try_new("Seventy70").is_ok());
+///
+/// // Unsuccessfully constructed because there are 2 character classes.
+/// assert!(Password::try_new("seventy70").is_err());
+/// ```
+pub struct min_classes(pub usize);
+
+impl<T> Validator<T> for min_classes
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+
+        let classes = [
+            has_lowercase.validate(&target),
+            has_uppercase.validate(&target),
+            has_digit.validate(&target),
+            has_symbol.validate(&target),
+        ];
+
+        classes.into_iter().filter(|class| *class).count() >= self.0
+    }
+}
+
+/// [`Validator`] checks if not a common password.
+///
+/// The password is compared case-insensitively against a bundled list of
+/// commonly used passwords.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::password::*, seventy, Newtype};
+///
+/// #[seventy(validate(not_common))]
+/// pub struct Password(String);
+///
+/// // Successfully constructed because the password is not common.
+/// assert!(Password::try_new("Seven-Tea-Cups-70").is_ok());
+///
+/// // Unsuccessfully constructed because the passwords are common.
+/// assert!(Password::try_new("password").is_err());
+/// assert!(Password::try_new("QWERTY").is_err());
+/// ```
+pub struct not_common;
+
+impl<T> Validator<T> for not_common
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref().to_lowercase();
+        !COMMON_PASSWORDS.contains(&target.as_str())
+    }
+}
+
+/// Commonly used passwords, ordered from most to least common.
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456",
+    "password",
+    "123456789",
+    "12345678",
+    "12345",
+    "qwerty",
+    "1234567",
+    "111111",
+    "1234567890",
+    "123123",
+    "abc123",
+    "1234",
+    "password1",
+    "iloveyou",
+    "1q2w3e4r",
+    "000000",
+    "qwerty123",
+    "zaq12wsx",
+    "dragon",
+    "sunshine",
+    "princess",
+    "letmein",
+    "654321",
+    "monkey",
+    "27653",
+    "1qaz2wsx",
+    "123321",
+    "qwertyuiop",
+    "superman",
+    "asdfghjkl",
+    "football",
+    "baseball",
+    "welcome",
+    "admin",
+    "login",
+    "master",
+    "hello",
+    "freedom",
+    "whatever",
+    "qazwsx",
+    "trustno1",
+    "starwars",
+    "shadow",
+    "michael",
+    "jennifer",
+    "hunter",
+    "batman",
+    "charlie",
+    "donald",
+    "passw0rd",
+    "solo",
+    "access",
+    "flower",
+    "hottie",
+    "loveme",
+    "zaq1zaq1",
+    "mustang",
+    "secret",
+    "summer",
+    "winter",
+    "spring",
+    "autumn",
+    "soccer",
+    "hockey",
+    "killer",
+    "pepper",
+    "ginger",
+    "jordan",
+    "harley",
+    "ranger",
+    "buster",
+    "thomas",
+    "tigger",
+    "robert",
+    "daniel",
+    "andrew",
+    "joshua",
+    "matthew",
+    "george",
+    "computer",
+    "internet",
+    "cheese",
+    "coffee",
+    "chocolate",
+    "butterfly",
+    "purple",
+    "orange",
+    "banana",
+    "cookie",
+    "maggie",
+    "pokemon",
+    "naruto",
+    "liverpool",
+    "chelsea",
+    "arsenal",
+    "samsung",
+    "google",
+    "apple",
+    "microsoft",
+    "changeme",
+    "default",
+    "guest",
+    "root",
+    "test",
+    "test123",
+    "user",
+    "administrator",
+    "qwe123",
+    "asd123",
+    "abcdef",
+    "abcd1234",
+    "aa123456",
+    "a123456",
+    "123qwe",
+    "987654321",
+    "666666",
+    "888888",
+    "121212",
+    "112233",
+    "696969",
+    "159753",
+    "147258369",
+    "11111111",
+    "00000000",
+    "123abc",
+    "password123",
+    "admin123",
+    "welcome1",
+    "iloveyou1",
+    "p@ssw0rd",
+    "monkey1",
+    "letmein1",
+];
+
+/// Guesses needed to brute force a single character.
+const BRUTEFORCE_GUESSES: f64 = 10.0;
+
+/// Keyboard rows used to detect keyboard patterns.
+const KEYBOARD_ROWS: &[&str] = &["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Estimates the base 10 logarithm of the number of guesses needed to crack
+/// the password.
+fn estimate_guesses_log10(password: &str) -> f64 {
+    let chars = password.chars().collect::<Vec<_>>();
+
+    let mut guesses = 0.0;
+    let mut start = 0;
+
+    while start < chars.len() {
+        let rest = &chars[start..];
+
+        let (length, segment_guesses) = [
+            common_password_match(rest),
+            repeat_match(rest),
+            sequence_match(rest),
+            keyboard_match(rest),
+        ]
+        .into_iter()
+        .flatten()
+        .max_by_key(|(length, _)| *length)
+        .unwrap_or((1, BRUTEFORCE_GUESSES));
+
+        guesses += segment_guesses.log10();
+        start += length;
+    }
+
+    guesses
+}
+
+/// Matches the longest common password prefix, returning the match length and
+/// guesses.
+fn common_password_match(chars: &[char]) -> Option<(usize, f64)> {
+    const MIN_LENGTH: usize = 4;
+
+    // Only look as far ahead as the longest common password, so each match is
+    // constant time.
+    let max_length = COMMON_PASSWORDS.iter().map(|common| common.len()).max()?;
+    let chars = &chars[..chars.len().min(max_length)];
+
+    let lowercase = chars
+        .iter()
+        .flat_map(|c| c.to_lowercase())
+        .collect::<String>();
+    let unleeted = chars.iter().map(|c| unleet(*c)).collect::<String>();
+
+    COMMON_PASSWORDS
+        .iter()
+        .enumerate()
+        .filter(|(_, common)| common.len() >= MIN_LENGTH)
+        .filter_map(|(rank, common)| {
+            let mut guesses = (rank + 1) as f64;
+
+            if !lowercase.starts_with(common) {
+                if !unleeted.starts_with(common) {
+                    return None;
+                }
+
+                guesses *= 2.0;
+            }
+
+            let matched = &chars[..common.chars().count()];
+
+            if matched.iter().any(|c| c.is_uppercase()) {
+                guesses *= 2.0;
+            }
+
+            Some((matched.len(), guesses))
+        })
+        .max_by_key(|(length, _)| *length)
+}
+
+/// Matches the longest run of a repeated character, returning the match length
+/// and guesses.
+fn repeat_match(chars: &[char]) -> Option<(usize, f64)> {
+    const MIN_LENGTH: usize = 3;
+
+    let length = chars.iter().take_while(|c| **c == chars[0]).count();
+
+    (length >= MIN_LENGTH).then_some((length, BRUTEFORCE_GUESSES * length as f64))
+}
+
+/// Matches the longest run of consecutive characters, such as `abcd` or `4321`,
+/// returning the match length and guesses.
+fn sequence_match(chars: &[char]) -> Option<(usize, f64)> {
+    const MIN_LENGTH: usize = 3;
+
+    let delta = (*chars.get(1)? as i64) - (chars[0] as i64);
+
+    if delta.abs() != 1 {
+        return None;
+    }
+
+    let length = 1 + chars
+        .windows(2)
+        .take_while(|window| (window[1] as i64) - (window[0] as i64) == delta)
+        .count();
+
+    if length < MIN_LENGTH {
+        return None;
+    }
+
+    let mut guesses = match chars[0] {
+        'a' | 'A' | 'z' | 'Z' | '0' | '1' | '9' => 4.0,
+        c if c.is_ascii_digit() => 10.0,
+        _ => 26.0,
+    };
+
+    if delta < 0 {
+        guesses *= 2.0;
+    }
+
+    Some((length, guesses * length as f64))
+}
+
+/// Matches the longest run of adjacent keys on a keyboard row, returning the
+/// match length and guesses.
+fn keyboard_match(chars: &[char]) -> Option<(usize, f64)> {
+    const MIN_LENGTH: usize = 4;
+
+    // Only look as far ahead as the longest keyboard row, so each match is
+    // constant time.
+    let max_length = KEYBOARD_ROWS.iter().map(|row| row.len()).max()?;
+
+    let lowercase = chars
+        .iter()
+        .take(max_length)
+        .map(|c| c.to_ascii_lowercase())
+        .collect::<String>();
+
+    KEYBOARD_ROWS
+        .iter()
+        .flat_map(|row| (0..row.len()).map(move |start| &row[start..]))
+        .map(|keys| {
+            lowercase
+                .bytes()
+                .zip(keys.bytes())
+                .take_while(|(c, key)| c == key)
+                .count()
+        })
+        .filter(|length| *length >= MIN_LENGTH)
+        .max()
+        .map(|length| (length, 10.0 * length as f64))
+}
+
+/// Checks if the character is a symbol.
+fn is_symbol(c: char) -> bool {
+    !c.is_alphanumeric() && !c.is_whitespace()
+}
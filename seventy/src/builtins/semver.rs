@@ -0,0 +1,135 @@
+//! Semantic versioning built-ins.
+
+use crate::core::Validator;
+
+pub use semver_util::{Version, VersionReq};
+
+/// [`Validator`] checks if a semantic version.
+///
+/// Follows [SemVer 2.0](https://semver.org) strictly, so all three numeric
+/// components are required and a leading `v` is not allowed.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::semver::*, seventy, Newtype};
+///
+/// #[seventy(validate(version))]
+/// pub struct PluginVersion(String);
+///
+/// // Successfully constructed because the strings are semantic versions.
+/// assert!(PluginVersion::try_new("7.0.0").is_ok());
+/// assert!(PluginVersion::try_new("7.0.0-beta.1+build.70").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not semantic versions.
+/// assert!(PluginVersion::try_new("7.0").is_err());
+/// assert!(PluginVersion::try_new("v7.0.0").is_err());
+/// assert!(PluginVersion::try_new("07.0.0").is_err());
+/// ```
+pub struct version;
+
+impl<T> Validator<T> for version
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        Version::parse(target.as_ref()).is_ok()
+    }
+}
+
+/// [`Validator`] forwards the parsed [`Version`] to inner validator.
+///
+/// If not a semantic version, the validation is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{compare::*, semver::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(version_then(ge(Version::new(1, 0, 0)))))]
+/// pub struct PluginVersion(String);
+///
+/// // Successfully constructed because the version is >= 1.0.0.
+/// assert!(PluginVersion::try_new("7.0.0").is_ok());
+///
+/// // Unsuccessfully constructed because the versions are not >= 1.0.0.
+/// assert!(PluginVersion::try_new("0.7.0").is_err());
+/// assert!(PluginVersion::try_new("1.0.0-rc.1").is_err());
+/// ```
+pub struct version_then<V>(pub V);
+
+impl<T, V> Validator<T> for version_then<V>
+where
+    T: AsRef<str>,
+    V: Validator<Version>,
+{
+    fn validate(&self, target: &T) -> bool {
+        Version::parse(target.as_ref()).is_ok_and(|parsed| self.0.validate(&parsed))
+    }
+}
+
+/// [`Validator`] checks if a version requirement.
+///
+/// Requirements are comma-separated comparators, such as `>=1.2, <2` or
+/// `^7.0`, using Cargo's syntax.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::semver::*, seventy, Newtype};
+///
+/// #[seventy(validate(requirement))]
+/// pub struct HostRequirement(String);
+///
+/// // Successfully constructed because the strings are version requirements.
+/// assert!(HostRequirement::try_new(">=1.2, <2").is_ok());
+/// assert!(HostRequirement::try_new("^7.0").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not a version requirement.
+/// assert!(HostRequirement::try_new("seventy").is_err());
+/// ```
+pub struct requirement;
+
+impl<T> Validator<T> for requirement
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        VersionReq::parse(target.as_ref()).is_ok()
+    }
+}
+
+/// [`Validator`] checks if a [`Version`] has no pre-release.
+///
+/// Use [`version_then`] to check strings.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::semver::*, seventy, Newtype};
+///
+/// #[seventy(validate(version_then(no_prerelease)))]
+/// pub struct StableVersion(String);
+///
+/// // Successfully constructed because the version has no pre-release.
+/// assert!(StableVersion::try_new("7.0.0+build.70").is_ok());
+///
+/// // Unsuccessfully constructed because the version has a pre-release.
+/// assert!(StableVersion::try_new("7.0.0-beta.1").is_err());
+///
+/// #[seventy(validate(no_prerelease))]
+/// pub struct StableRelease(Version);
+///
+/// // Successfully constructed because the version has no pre-release.
+/// assert!(StableRelease::try_new(Version::new(7, 0, 0)).is_ok());
+/// ```
+pub struct no_prerelease;
+
+impl Validator<Version> for no_prerelease {
+    fn validate(&self, target: &Version) -> bool {
+        target.pre.is_empty()
+    }
+}
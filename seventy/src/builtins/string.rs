@@ -1,7 +1,49 @@
 //! String built-ins.
 
+use std::{borrow::Cow, marker::PhantomData, str::FromStr};
+
 use crate::core::{Sanitizer, Validator};
 
+/// Owned string that string [`Sanitizer`]s can be applied to.
+///
+/// Implemented for [`String`], [`Box<str>`], and [`Cow<str>`].
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(trim, lowercase))]
+/// pub struct Tag(Box<str>);
+///
+/// // Sanitizes the boxed string.
+/// assert_eq!(&*Tag::try_new("  SeVeNTy  ").unwrap().into_inner(), "seventy");
+/// ```
+pub trait OwnedString {
+    /// Mutates the string as a [`String`].
+    fn with_string(&mut self, f: impl FnOnce(&mut String));
+}
+
+impl OwnedString for String {
+    fn with_string(&mut self, f: impl FnOnce(&mut String)) {
+        f(self);
+    }
+}
+
+impl OwnedString for Box<str> {
+    fn with_string(&mut self, f: impl FnOnce(&mut String)) {
+        let mut string = String::from(std::mem::take(self));
+        f(&mut string);
+        *self = string.into_boxed_str();
+    }
+}
+
+impl OwnedString for Cow<'_, str> {
+    fn with_string(&mut self, f: impl FnOnce(&mut String)) {
+        f(self.to_mut());
+    }
+}
+
 /// [`Sanitizer`] trims whitespace.
 ///
 /// # Examples
@@ -20,10 +62,13 @@ use crate::core::{Sanitizer, Validator};
 /// ```
 pub struct trim;
 
-impl Sanitizer<String> for trim {
-    fn sanitize(&self, target: &mut String) {
+impl<T> Sanitizer<T> for trim
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
         // OPTIMIZE: Try trimming in-place.
-        *target = target.trim().to_string();
+        target.with_string(|target| *target = target.trim().to_string());
     }
 }
 
@@ -45,10 +90,13 @@ impl Sanitizer<String> for trim {
 /// ```
 pub struct trim_left;
 
-impl Sanitizer<String> for trim_left {
-    fn sanitize(&self, target: &mut String) {
+impl<T> Sanitizer<T> for trim_left
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
         // OPTIMIZE: Try trimming in-place.
-        *target = target.trim_start().to_string();
+        target.with_string(|target| *target = target.trim_start().to_string());
     }
 }
 
@@ -70,293 +118,3208 @@ impl Sanitizer<String> for trim_left {
 /// ```
 pub struct trim_right;
 
-impl Sanitizer<String> for trim_right {
-    fn sanitize(&self, target: &mut String) {
+impl<T> Sanitizer<T> for trim_right
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
         // OPTIMIZE: Try trimming in-place.
-        *target = target.trim_end().to_string();
+        target.with_string(|target| *target = target.trim_end().to_string());
     }
 }
 
-/// [`Validator`] checks if only alphabetic.
+/// [`Sanitizer`] trims the given characters.
 ///
 /// # Examples
 ///
 /// ```
 /// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(validate(alphabetic))]
-/// pub struct FirstName(String);
-///
-/// // Successfully constructed because the string is only alphabetic.
-/// assert!(FirstName::try_new("Michael").is_ok());
+/// #[seventy(sanitize(trim_matches(['-', '_'])))]
+/// pub struct Handle(String);
 ///
-/// // Unsuccessfully constructed because the string is not only alphabetic.
-/// assert!(FirstName::try_new("Mich4el").is_err());
+/// // Trims surrounding dashes and underscores.
+/// assert_eq!(
+///     Handle::try_new("__-seventy_70-__").unwrap().into_inner(),
+///     "seventy_70"
+/// );
 /// ```
-pub struct alphabetic;
+pub struct trim_matches<C>(pub C);
 
-impl<T> Validator<T> for alphabetic
+impl<T, C> Sanitizer<T> for trim_matches<C>
 where
-    T: AsRef<str>,
+    T: OwnedString,
+    C: AsRef<[char]>,
 {
-    fn validate(&self, target: &T) -> bool {
-        target.as_ref().chars().all(char::is_alphabetic)
+    fn sanitize(&self, target: &mut T) {
+        let chars = self.0.as_ref();
+
+        target.with_string(|target| {
+            let end = target.trim_end_matches(chars).len();
+            target.truncate(end);
+
+            let start = end - target.trim_start_matches(chars).len();
+            target.drain(..start);
+        });
     }
 }
 
-/// [`Validator`] checks if only alphanumeric.
+/// [`Sanitizer`] removes the prefix, if present.
 ///
 /// # Examples
 ///
 /// ```
 /// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(validate(alphanumeric))]
-/// pub struct Username(String);
+/// #[seventy(sanitize(strip_prefix("@")))]
+/// pub struct Handle(String);
 ///
-/// // Successfully constructed because the string is only alphanumeric.
-/// assert!(Username::try_new("Seventy70").is_ok());
+/// // Removes the prefix.
+/// assert_eq!(Handle::try_new("@seventy").unwrap().into_inner(), "seventy");
 ///
-/// // Unsuccessfully constructed because the string is not only alphanumeric.
-/// assert!(Username::try_new("Seventy#70!").is_err());
+/// // No changes, because the prefix is not present.
+/// assert_eq!(Handle::try_new("seventy").unwrap().into_inner(), "seventy");
 /// ```
-pub struct alphanumeric;
+pub struct strip_prefix<P>(pub P);
 
-impl<T> Validator<T> for alphanumeric
+impl<T, P> Sanitizer<T> for strip_prefix<P>
 where
-    T: AsRef<str>,
+    T: OwnedString,
+    P: AsRef<str>,
 {
-    fn validate(&self, target: &T) -> bool {
-        target.as_ref().chars().all(char::is_alphanumeric)
+    fn sanitize(&self, target: &mut T) {
+        let prefix = self.0.as_ref();
+
+        target.with_string(|target| {
+            if target.starts_with(prefix) {
+                target.drain(..prefix.len());
+            }
+        });
     }
 }
 
-/// [`Validator`] checks if only ASCII.
+/// [`Sanitizer`] removes the suffix, if present.
 ///
 /// # Examples
 ///
 /// ```
 /// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(validate(ascii))]
-/// pub struct Password(String);
+/// #[seventy(sanitize(strip_suffix(".git")))]
+/// pub struct Repository(String);
 ///
-/// // Successfully constructed because the string is only ASCII.
-/// assert!(Password::try_new("Seventy#70!").is_ok());
+/// // Removes the suffix.
+/// assert_eq!(
+///     Repository::try_new("seventy.git").unwrap().into_inner(),
+///     "seventy"
+/// );
 ///
-/// // Unsuccessfully constructed because the string is not only ASCII.
-/// assert!(Password::try_new("Seventy\u{7070}#70!").is_err());
+/// // No changes, because the suffix is not present.
+/// assert_eq!(
+///     Repository::try_new("seventy").unwrap().into_inner(),
+///     "seventy"
+/// );
 /// ```
-pub struct ascii;
+pub struct strip_suffix<P>(pub P);
 
-impl<T> Validator<T> for ascii
+impl<T, P> Sanitizer<T> for strip_suffix<P>
 where
-    T: AsRef<str>,
+    T: OwnedString,
+    P: AsRef<str>,
 {
-    fn validate(&self, target: &T) -> bool {
-        target.as_ref().is_ascii()
+    fn sanitize(&self, target: &mut T) {
+        let suffix = self.0.as_ref();
+
+        target.with_string(|target| {
+            if target.ends_with(suffix) {
+                target.truncate(target.len() - suffix.len());
+            }
+        });
     }
 }
 
-/// [`Sanitizer`] converts to lowercase. [`Validator`] checks if only lowercase.
+/// [`Sanitizer`] collapses runs of whitespace into a single space (U+0020).
 ///
 /// # Examples
 ///
-/// An example of the `lowercase` validator.
-///
 /// ```
 /// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(sanitize(lowercase))]
-/// pub struct SearchQuery(String);
+/// #[seventy(sanitize(trim, collapse_whitespace))]
+/// pub struct DisplayName(String);
 ///
-/// // Converts uppercase characters to lowercase.
+/// // Trims surrounding whitespace and collapses inner whitespace.
 /// assert_eq!(
-///     SearchQuery::try_new("What is the SeVeNTy crate?!")
-///         .unwrap()
-///         .into_inner(),
-///     "what is the seventy crate?!"
+///     DisplayName::try_new("  Seven \t\n Tea  ").unwrap().into_inner(),
+///     "Seven Tea"
 /// );
 /// ```
+pub struct collapse_whitespace;
+
+impl<T> Sanitizer<T> for collapse_whitespace
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| {
+            let mut collapsed = String::with_capacity(target.len());
+            let mut previous_whitespace = false;
+
+            for c in target.chars() {
+                if c.is_whitespace() {
+                    if !previous_whitespace {
+                        collapsed.push(' ');
+                    }
+                    previous_whitespace = true;
+                } else {
+                    collapsed.push(c);
+                    previous_whitespace = false;
+                }
+            }
+
+            *target = collapsed;
+        });
+    }
+}
+
+/// [`Sanitizer`] removes all whitespace.
 ///
-/// An example of the `lowercase` sanitizer.
+/// # Examples
 ///
 /// ```
 /// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(validate(lowercase))]
-/// pub struct LowercaseString(String);
-///
-/// // Successfully constructed because the string is only lowercase.
-/// assert!(LowercaseString::try_new("whisper").is_ok());
+/// #[seventy(sanitize(remove_whitespace))]
+/// pub struct PhoneNumber(String);
 ///
-/// // Unsuccessfully constructed because the string is not only lowercase.
-/// assert!(LowercaseString::try_new("Whisper").is_err());
+/// // Removes all whitespace.
+/// assert_eq!(
+///     PhoneNumber::try_new(" 555 070 7070 ").unwrap().into_inner(),
+///     "5550707070"
+/// );
 /// ```
-pub struct lowercase;
+pub struct remove_whitespace;
 
-impl Sanitizer<String> for lowercase {
-    fn sanitize(&self, target: &mut String) {
-        *target = target.to_lowercase();
-    }
-}
-
-impl<T> Validator<T> for lowercase
+impl<T> Sanitizer<T> for remove_whitespace
 where
-    T: AsRef<str>,
+    T: OwnedString,
 {
-    fn validate(&self, target: &T) -> bool {
-        target.as_ref().chars().all(char::is_lowercase)
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| target.retain(|c| !c.is_whitespace()));
     }
 }
 
-/// [`Sanitizer`] converts to uppercase. [`Validator`] checks if only uppercase.
+/// [`Sanitizer`] converts line endings.
 ///
-/// # Examples
+/// Line endings are `\r\n`, `\r`, or `\n`. With `normalize_lf`, each line
+/// ending is converted to `\n`. With `to_spaces`, each line ending is
+/// converted to a space (U+0020), joining the lines.
 ///
-/// An example of the `uppercase` sanitizer.
+/// # Examples
 ///
 /// ```
 /// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(sanitize(uppercase))]
-/// pub struct ID(String);
+/// #[seventy(sanitize(newlines::normalize_lf))]
+/// pub struct Description(String);
 ///
-/// // Converts lowercase characters to uppercase.
+/// // Converts the mixed line endings to `\n`.
 /// assert_eq!(
-///     ID::try_new("70sV-Nty70").unwrap().into_inner(),
-///     "70SV-NTY70"
+///     Description::try_new("Seven\r\nTea\rCups\n").unwrap().into_inner(),
+///     "Seven\nTea\nCups\n"
+/// );
+///
+/// #[seventy(sanitize(newlines::to_spaces))]
+/// pub struct LogMessage(String);
+///
+/// // Joins the lines, so the message can't forge log entries.
+/// assert_eq!(
+///     LogMessage::try_new("login failed\r\nlogin succeeded").unwrap().into_inner(),
+///     "login failed login succeeded"
 /// );
 /// ```
+pub enum newlines {
+    normalize_lf,
+    to_spaces,
+}
+
+impl<T> Sanitizer<T> for newlines
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        let replacement = match self {
+            Self::normalize_lf => '\n',
+            Self::to_spaces => ' ',
+        };
+
+        target.with_string(|target| {
+            if !target.contains(['\r', '\n']) {
+                return;
+            }
+
+            let mut converted = String::with_capacity(target.len());
+            let mut chars = target.chars().peekable();
+
+            while let Some(c) = chars.next() {
+                match c {
+                    '\r' => {
+                        chars.next_if_eq(&'\n');
+                        converted.push(replacement);
+                    }
+                    '\n' => converted.push(replacement),
+                    c => converted.push(c),
+                }
+            }
+
+            *target = converted;
+        });
+    }
+}
+
+/// [`Validator`] checks if a single line.
 ///
-/// An example of the `uppercase` validator.
+/// A single line contains no `\r` or `\n`.
+///
+/// # Examples
 ///
 /// ```
 /// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(validate(uppercase))]
-/// pub struct UppercaseString(String);
+/// #[seventy(validate(single_line))]
+/// pub struct CsvField(String);
 ///
-/// // Successfully constructed because the string is only uppercase.
-/// assert!(UppercaseString::try_new("SHOUT").is_ok());
+/// // Successfully constructed because the string is a single line.
+/// assert!(CsvField::try_new("Seven Tea").is_ok());
 ///
-/// // Unsuccessfully constructed because the string is not only uppercase.
-/// assert!(UppercaseString::try_new("Shout").is_err());
+/// // Unsuccessfully constructed because the strings are multiple lines.
+/// assert!(CsvField::try_new("Seven\nTea").is_err());
+/// assert!(CsvField::try_new("Seven\rTea").is_err());
 /// ```
-pub struct uppercase;
-
-impl Sanitizer<String> for uppercase {
-    fn sanitize(&self, target: &mut String) {
-        *target = target.to_uppercase();
-    }
-}
+pub struct single_line;
 
-impl<T> Validator<T> for uppercase
+impl<T> Validator<T> for single_line
 where
     T: AsRef<str>,
 {
     fn validate(&self, target: &T) -> bool {
-        target.as_ref().chars().all(char::is_uppercase)
+        !target.as_ref().contains(['\r', '\n'])
     }
 }
 
-/// [`Validator`] forwards length to inner validator.
+/// [`Validator`] checks if safe as an HTTP header value.
+///
+/// A value is header-safe if it contains no `\r`, `\n`, or NUL, which prevents
+/// header injection (CRLF injection).
 ///
 /// # Examples
 ///
 /// ```
-/// use seventy::{
-///     builtins::{compare::*, string::*},
-///     seventy, Newtype,
-/// };
+/// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(validate(length::chars(le(15))))]
-/// pub struct TextBox(String);
+/// #[seventy(validate(header_safe))]
+/// pub struct RedirectLocation(String);
 ///
-/// // Successfully constructed because the string is <= 15 characters.
-/// assert!(TextBox::try_new("Hello, World!").is_ok());
+/// // Successfully constructed because the string is header-safe.
+/// assert!(RedirectLocation::try_new("/seventy").is_ok());
 ///
-/// // Unsuccessfully constructed because the string is not <= 15 characters.
-/// assert!(TextBox::try_new("Hello, World! I am hungry.").is_err());
+/// // Unsuccessfully constructed because the strings could inject headers.
+/// assert!(RedirectLocation::try_new("/seventy\r\nSet-Cookie: session=70").is_err());
+/// assert!(RedirectLocation::try_new("/seventy\0").is_err());
 /// ```
-pub enum length<V> {
-    bytes(V),
-    chars(V),
-}
+pub struct header_safe;
 
-impl<T, V> Validator<T> for length<V>
+impl<T> Validator<T> for header_safe
 where
     T: AsRef<str>,
-    V: Validator<usize>,
 {
     fn validate(&self, target: &T) -> bool {
-        let target = target.as_ref();
+        !target.as_ref().contains(['\r', '\n', '\0'])
+    }
+}
 
-        match self {
-            Self::bytes(v) => v.validate(&target.len()),
-            Self::chars(v) => v.validate(&target.chars().count()),
-        }
+/// [`Sanitizer`] removes characters that satisfy the predicate.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(remove_chars(char::is_control)))]
+/// pub struct Comment(String);
+///
+/// // Removes the control characters.
+/// assert_eq!(
+///     Comment::try_new("Seventy\u{0007}!\u{0000}").unwrap().into_inner(),
+///     "Seventy!"
+/// );
+/// ```
+pub struct remove_chars<F>(pub F);
+
+impl<T, F> Sanitizer<T> for remove_chars<F>
+where
+    T: OwnedString,
+    F: Fn(char) -> bool,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| target.retain(|c| !(self.0)(c)));
     }
 }
 
-/// [`Validator`] checks if not empty.
+/// [`Sanitizer`] keeps only characters that satisfy the predicate.
 ///
 /// # Examples
 ///
 /// ```
 /// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(validate(not_empty))]
-/// pub struct TextBox(String);
+/// #[seventy(sanitize(keep_chars(char::is_numeric)))]
+/// pub struct PhoneNumber(String);
 ///
-/// // Successfully constructed because the string is not empty.
-/// assert!(TextBox::try_new("Hello, World!").is_ok());
+/// // Keeps only the numeric characters.
+/// assert_eq!(
+///     PhoneNumber::try_new("(555) 070-7070").unwrap().into_inner(),
+///     "5550707070"
+/// );
+/// ```
+pub struct keep_chars<F>(pub F);
+
+impl<T, F> Sanitizer<T> for keep_chars<F>
+where
+    T: OwnedString,
+    F: Fn(char) -> bool,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| target.retain(|c| (self.0)(c)));
+    }
+}
+
+/// [`Sanitizer`] replaces all matches of a pattern with another string.
+///
+/// An empty pattern matches nothing, so the string is left unchanged.
+///
+/// # Examples
 ///
-/// // Unsuccessfully constructed because the string is empty.
-/// assert!(TextBox::try_new("").is_err());
 /// ```
-pub struct not_empty;
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(replace("_", "-")))]
+/// pub struct Slug(String);
+///
+/// // Replaces underscores with dashes.
+/// assert_eq!(
+///     Slug::try_new("seven_tea_inc").unwrap().into_inner(),
+///     "seven-tea-inc"
+/// );
+/// ```
+pub struct replace<F, R>(pub F, pub R);
 
-impl<T> Validator<T> for not_empty
+impl<T, F, R> Sanitizer<T> for replace<F, R>
 where
-    T: AsRef<str>,
+    T: OwnedString,
+    F: AsRef<str>,
+    R: AsRef<str>,
 {
-    fn validate(&self, target: &T) -> bool {
-        !target.as_ref().is_empty()
+    fn sanitize(&self, target: &mut T) {
+        let from = self.0.as_ref();
+        let to = self.1.as_ref();
+
+        target.with_string(|target| {
+            if !from.is_empty() && target.contains(from) {
+                *target = target.replace(from, to);
+            }
+        });
     }
 }
 
-/// [`Validator`] checks if matches regex.
+/// [`Sanitizer`] truncates to a maximum length.
+///
+/// Truncation never splits a character. The `graphemes` variant, which never
+/// splits a user-perceived character, requires the `unicode` feature.
+///
+/// An ellipsis can be appended to truncated strings with
+/// [`truncate::ellipsis`]. The ellipsis counts toward the maximum length.
 ///
 /// # Examples
 ///
 /// ```
 /// use seventy::{builtins::string::*, seventy, Newtype};
 ///
-/// #[seventy(validate(regex(r"^[A-Z]([a-zA-Z0-9]|[- @\.#&!])*$")))]
-/// pub struct Company(String);
+/// #[seventy(sanitize(truncate::chars(10)))]
+/// pub struct DisplayName(String);
 ///
-/// // Successfully constructed because "Seven Tea Inc." matches the regex.
-/// assert!(Company::try_new("Seven Tea Inc.").is_ok());
+/// // Truncates to 10 characters.
+/// assert_eq!(
+///     DisplayName::try_new("Seventy Seventy").unwrap().into_inner(),
+///     "Seventy Se"
+/// );
 ///
-/// // Unsuccessfuly constructed because "Seven \u{1F375} Inc." does not match the regex.
-/// assert!(Company::try_new("Seven \u{1F375} Inc.").is_err());
+/// // No changes, because within 10 characters.
+/// assert_eq!(
+///     DisplayName::try_new("Seventy").unwrap().into_inner(),
+///     "Seventy"
+/// );
 /// ```
-#[cfg(feature = "regex")]
-pub fn regex(regex: &'static str) -> _regex {
-    _regex(regex_util::Regex::new(regex).unwrap())
+///
+/// Truncating bytes backs off to the nearest character boundary.
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(truncate::bytes(4)))]
+/// pub struct Column(String);
+///
+/// // '\u{7070}' is 3 bytes, so only one fits in 4 bytes.
+/// assert_eq!(
+///     Column::try_new("\u{7070}\u{7070}").unwrap().into_inner(),
+///     "\u{7070}"
+/// );
+/// ```
+#[non_exhaustive]
+pub enum truncate {
+    bytes(usize),
+    chars(usize),
+    /// Truncating graphemes keeps combining marks with their base character.
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(truncate::graphemes(2)))]
+    /// pub struct Initials(String);
+    ///
+    /// // "e\u{301}" is a single grapheme made of two characters.
+    /// assert_eq!(
+    ///     Initials::try_new("e\u{301}e\u{301}e\u{301}")
+    ///         .unwrap()
+    ///         .into_inner(),
+    ///     "e\u{301}e\u{301}"
+    /// );
+    /// ```
+    #[cfg(feature = "unicode")]
+    graphemes(usize),
 }
 
-#[doc(hidden)]
-#[cfg(feature = "regex")]
-pub struct _regex(regex_util::Regex);
+impl truncate {
+    /// Appends the ellipsis to truncated strings.
+    ///
+    /// The ellipsis is measured in the same unit as the truncation and counts
+    /// toward the maximum length, unless the ellipsis itself is longer than
+    /// the maximum length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(truncate::chars(10).ellipsis("...")))]
+    /// pub struct Preview(String);
+    ///
+    /// // Truncates to 10 characters, including the ellipsis.
+    /// assert_eq!(
+    ///     Preview::try_new("Seventy is a cool crate.")
+    ///         .unwrap()
+    ///         .into_inner(),
+    ///     "Seventy..."
+    /// );
+    ///
+    /// // No changes, because within 10 characters.
+    /// assert_eq!(Preview::try_new("Seventy!").unwrap().into_inner(), "Seventy!");
+    /// ```
+    pub fn ellipsis<E>(self, ellipsis: E) -> truncate_ellipsis<E> {
+        truncate_ellipsis(self, ellipsis)
+    }
 
-#[cfg(feature = "regex")]
-impl<T> Validator<T> for _regex
-where
-    T: AsRef<str>,
-{
-    fn validate(&self, target: &T) -> bool {
-        self.0.is_match(target.as_ref())
+    /// Returns the maximum length.
+    fn max(&self) -> usize {
+        match self {
+            Self::bytes(max) | Self::chars(max) => *max,
+            #[cfg(feature = "unicode")]
+            Self::graphemes(max) => *max,
+        }
+    }
+
+    /// Returns the length of the string, measured in the truncation's unit.
+    fn len(&self, target: &str) -> usize {
+        match self {
+            Self::bytes(_) => target.len(),
+            Self::chars(_) => target.chars().count(),
+            #[cfg(feature = "unicode")]
+            Self::graphemes(_) => {
+                use unicode_segmentation_util::UnicodeSegmentation;
+
+                target.graphemes(true).count()
+            }
+        }
+    }
+
+    /// Returns the byte index to truncate the string at so that it is within
+    /// the given length, or [`None`] if already within the given length.
+    fn boundary(&self, target: &str, max: usize) -> Option<usize> {
+        match self {
+            Self::bytes(_) => (target.len() > max).then(|| {
+                let mut index = max;
+                while !target.is_char_boundary(index) {
+                    index -= 1;
+                }
+                index
+            }),
+            Self::chars(_) => target.char_indices().nth(max).map(|(index, _)| index),
+            #[cfg(feature = "unicode")]
+            Self::graphemes(_) => {
+                use unicode_segmentation_util::UnicodeSegmentation;
+
+                target
+                    .grapheme_indices(true)
+                    .nth(max)
+                    .map(|(index, _)| index)
+            }
+        }
+    }
+}
+
+impl<T> Sanitizer<T> for truncate
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| {
+            if let Some(index) = self.boundary(target, self.max()) {
+                target.truncate(index);
+            }
+        });
+    }
+}
+
+/// [`Sanitizer`] truncates to a maximum length, appending an ellipsis if
+/// truncated.
+///
+/// Constructed with [`truncate::ellipsis`].
+pub struct truncate_ellipsis<E>(pub truncate, pub E);
+
+impl<T, E> Sanitizer<T> for truncate_ellipsis<E>
+where
+    T: OwnedString,
+    E: AsRef<str>,
+{
+    fn sanitize(&self, target: &mut T) {
+        let Self(truncate, ellipsis) = self;
+        let ellipsis = ellipsis.as_ref();
+
+        target.with_string(|target| {
+            let max = truncate.max();
+
+            if truncate.boundary(target, max).is_none() {
+                return;
+            }
+
+            let max = max.saturating_sub(truncate.len(ellipsis));
+
+            if let Some(index) = truncate.boundary(target, max) {
+                target.truncate(index);
+            }
+
+            target.push_str(ellipsis);
+        });
+    }
+}
+
+/// [`Sanitizer`] pads the left side with a character until the given character
+/// length is reached.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(pad_left(6, '0')))]
+/// pub struct ProductCode(String);
+///
+/// // Pads the left with zeros.
+/// assert_eq!(ProductCode::try_new("70").unwrap().into_inner(), "000070");
+///
+/// // No changes, because already 6 characters.
+/// assert_eq!(ProductCode::try_new("707070").unwrap().into_inner(), "707070");
+/// ```
+pub struct pad_left(pub usize, pub char);
+
+impl<T> Sanitizer<T> for pad_left
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        let Self(width, fill) = *self;
+
+        target.with_string(|target| {
+            let count = target.chars().count();
+
+            if count < width {
+                let padding = fill.to_string().repeat(width - count);
+                target.insert_str(0, &padding);
+            }
+        });
+    }
+}
+
+/// [`Sanitizer`] pads the right side with a character until the given
+/// character length is reached.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(pad_right(6, ' ')))]
+/// pub struct FixedWidthField(String);
+///
+/// // Pads the right with spaces.
+/// assert_eq!(
+///     FixedWidthField::try_new("70").unwrap().into_inner(),
+///     "70    "
+/// );
+/// ```
+pub struct pad_right(pub usize, pub char);
+
+impl<T> Sanitizer<T> for pad_right
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        let Self(width, fill) = *self;
+
+        target.with_string(|target| {
+            let count = target.chars().count();
+
+            if count < width {
+                target.extend(std::iter::repeat_n(fill, width - count));
+            }
+        });
+    }
+}
+
+/// [`Sanitizer`] converts to a case.
+///
+/// Words are separated by non-alphanumeric characters, by a lowercase or
+/// numeric character followed by an uppercase character, and by the last
+/// uppercase character of an acronym followed by a lowercase character. Word
+/// boundaries are Unicode-aware.
+///
+/// | Variant           | Example         |
+/// | ----------------- | --------------- |
+/// | `snake`           | `seven_tea_inc` |
+/// | `kebab`           | `seven-tea-inc` |
+/// | `camel`           | `sevenTeaInc`   |
+/// | `pascal`          | `SevenTeaInc`   |
+/// | `screaming_snake` | `SEVEN_TEA_INC` |
+/// | `title`           | `Seven Tea Inc` |
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(case::snake))]
+/// pub struct Identifier(String);
+///
+/// // Converts to snake case.
+/// assert_eq!(
+///     Identifier::try_new("parseHTTPResponse").unwrap().into_inner(),
+///     "parse_http_response"
+/// );
+///
+/// #[seventy(sanitize(case::title))]
+/// pub struct DisplayName(String);
+///
+/// // Converts to title case.
+/// assert_eq!(
+///     DisplayName::try_new("\u{C9}LODIE_du-PR\u{C9}").unwrap().into_inner(),
+///     "\u{C9}lodie Du Pr\u{E9}"
+/// );
+/// ```
+pub enum case {
+    snake,
+    kebab,
+    camel,
+    pascal,
+    screaming_snake,
+    title,
+}
+
+impl case {
+    /// Converts the string to the case.
+    fn convert(&self, target: &str) -> String {
+        let parts = split_words(target);
+
+        match self {
+            Self::snake => join_words(&parts, "_", str::to_lowercase),
+            Self::kebab => join_words(&parts, "-", str::to_lowercase),
+            Self::screaming_snake => join_words(&parts, "_", str::to_uppercase),
+            Self::pascal => join_words(&parts, "", capitalize),
+            Self::title => join_words(&parts, " ", capitalize),
+            Self::camel => {
+                let mut camel = join_words(&parts, "", capitalize);
+
+                if let Some(first) = parts.first() {
+                    let capitalized = capitalize(first);
+                    camel.replace_range(..capitalized.len(), &first.to_lowercase());
+                }
+
+                camel
+            }
+        }
+    }
+}
+
+impl<T> Sanitizer<T> for case
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| *target = self.convert(target));
+    }
+}
+
+/// [`Validator`] checks if in a case.
+///
+/// A string is in a case if converting it with [`case`] makes no changes.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(is_case::kebab))]
+/// pub struct Slug(String);
+///
+/// // Successfully constructed because the string is in kebab case.
+/// assert!(Slug::try_new("seven-tea-inc").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not in kebab case.
+/// assert!(Slug::try_new("seven_tea_inc").is_err());
+/// assert!(Slug::try_new("seven--tea-inc").is_err());
+/// assert!(Slug::try_new("Seven-Tea-Inc").is_err());
+/// ```
+pub enum is_case {
+    snake,
+    kebab,
+    camel,
+    pascal,
+    screaming_snake,
+    title,
+}
+
+impl<T> Validator<T> for is_case
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+
+        let case = match self {
+            Self::snake => case::snake,
+            Self::kebab => case::kebab,
+            Self::camel => case::camel,
+            Self::pascal => case::pascal,
+            Self::screaming_snake => case::screaming_snake,
+            Self::title => case::title,
+        };
+
+        case.convert(target) == target
+    }
+}
+
+/// Splits the string into words.
+fn split_words(target: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+
+    for run in target.split(|c: char| !c.is_alphanumeric()) {
+        let mut start = 0;
+        let mut chars = run.char_indices().peekable();
+        let mut previous = None;
+
+        while let Some((index, c)) = chars.next() {
+            if let Some(previous) = previous {
+                let next = chars.peek().map(|&(_, next)| next);
+
+                let boundary = c.is_uppercase()
+                    && (char::is_lowercase(previous)
+                        || char::is_numeric(previous)
+                        || (char::is_uppercase(previous) && next.is_some_and(char::is_lowercase)));
+
+                if boundary {
+                    parts.push(&run[start..index]);
+                    start = index;
+                }
+            }
+
+            previous = Some(c);
+        }
+
+        if start < run.len() {
+            parts.push(&run[start..]);
+        }
+    }
+
+    parts
+}
+
+/// Converts each word and joins them with the separator.
+fn join_words(parts: &[&str], separator: &str, convert: impl Fn(&str) -> String) -> String {
+    parts
+        .iter()
+        .map(|word| convert(word))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Converts the first character to uppercase and the rest to lowercase.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.as_str().to_lowercase().chars())
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// [`Sanitizer`] converts to a URL slug, separated by dashes.
+///
+/// Latin letters without a decomposition, such as `ß`, `æ`, `ı`, `œ`, and
+/// `þ`, are transliterated. Other characters are decomposed and diacritics are
+/// stripped. The remaining ASCII alphanumeric characters are converted to
+/// lowercase, and other letters are removed. Runs of any other characters are
+/// replaced with a single separator, and surrounding separators are trimmed.
+///
+/// Other scripts are not transliterated, so a string with no Latin letters or
+/// digits, such as `"\u{6771}\u{4EAC}"`, is converted to an empty slug.
+///
+/// See [`slugify_with`] to configure the separator and maximum length.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(slugify))]
+/// pub struct Slug(String);
+///
+/// // Converts to a slug.
+/// assert_eq!(
+///     Slug::try_new("  Cr\u{E8}me Br\u{FB}l\u{E9}e -- Recipe!  ")
+///         .unwrap()
+///         .into_inner(),
+///     "creme-brulee-recipe"
+/// );
+///
+/// // Transliterates letters without a decomposition.
+/// assert_eq!(
+///     Slug::try_new("Stra\u{DF}e \u{D8}resund \u{141}\u{F3}d\u{17A}")
+///         .unwrap()
+///         .into_inner(),
+///     "strasse-oresund-lodz"
+/// );
+///
+/// // Transliterates Turkish and French letters without splitting words.
+/// assert_eq!(
+///     Slug::try_new("K\u{131}rm\u{131}z\u{131} I\u{15F}\u{131}k, C\u{153}ur de Lion")
+///         .unwrap()
+///         .into_inner(),
+///     "kirmizi-isik-coeur-de-lion"
+/// );
+/// ```
+#[cfg(feature = "unicode")]
+pub struct slugify;
+
+#[cfg(feature = "unicode")]
+impl<T> Sanitizer<T> for slugify
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        slugify_with {
+            separator: '-',
+            max_length: None,
+        }
+        .sanitize(target);
+    }
+}
+
+/// [`Sanitizer`] converts to a URL slug, with the given separator and optional
+/// maximum length.
+///
+/// The separator should be a character that is not ASCII alphanumeric. If the
+/// slug is longer than the maximum length in bytes, it is truncated at the
+/// last word boundary within the maximum length, or mid-word if the first word
+/// is too long.
+///
+/// See [`slugify`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(slugify_with { separator: '_', max_length: Some(16) }))]
+/// pub struct Slug(String);
+///
+/// // Converts to a slug separated by underscores, with at most 16 bytes.
+/// assert_eq!(
+///     Slug::try_new("Seven Tea Inc. Annual Report")
+///         .unwrap()
+///         .into_inner(),
+///     "seven_tea_inc"
+/// );
+///
+/// #[seventy(sanitize(slugify_with { separator: '\u{2013}', max_length: Some(3) }))]
+/// pub struct ShortSlug(String);
+///
+/// // The separator is 3 bytes, so only the first word fits in 3 bytes.
+/// assert_eq!(ShortSlug::try_new("ab cd ef").unwrap().into_inner(), "ab");
+/// ```
+#[cfg(feature = "unicode")]
+pub struct slugify_with {
+    pub separator: char,
+    pub max_length: Option<usize>,
+}
+
+#[cfg(feature = "unicode")]
+impl<T> Sanitizer<T> for slugify_with
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        use unicode_normalization_util::{char::is_combining_mark, UnicodeNormalization};
+
+        target.with_string(|target| {
+            // Letters that have no decomposition are transliterated first.
+            let mut transliterated = String::with_capacity(target.len());
+
+            for c in target.chars() {
+                match transliterate(c) {
+                    Some(part) => transliterated.push_str(part),
+                    None => transliterated.push(c),
+                }
+            }
+
+            let mut slugified = String::with_capacity(transliterated.len());
+            let mut separate = false;
+
+            for c in transliterated.nfkd().filter(|c| !is_combining_mark(*c)) {
+                if !c.is_ascii_alphanumeric() {
+                    // Other letters are removed without splitting the word.
+                    if !c.is_alphabetic() {
+                        separate = true;
+                    }
+
+                    continue;
+                }
+
+                if separate && !slugified.is_empty() {
+                    slugified.push(self.separator);
+                }
+
+                slugified.push(c.to_ascii_lowercase());
+                separate = false;
+            }
+
+            if let Some(max_length) = self.max_length {
+                if slugified.len() > max_length {
+                    // The separator may not be ASCII, so cut at a character boundary.
+                    let cut = (0..=max_length)
+                        .rev()
+                        .find(|&i| slugified.is_char_boundary(i))
+                        .unwrap_or(0);
+
+                    // Truncate at the last word boundary, if there is one.
+                    let boundary = if slugified[cut..].starts_with(self.separator) {
+                        cut
+                    } else {
+                        slugified[..cut].rfind(self.separator).unwrap_or(cut)
+                    };
+
+                    slugified.truncate(boundary);
+                }
+            }
+
+            *target = slugified;
+        });
+    }
+}
+
+/// Transliterates a Latin letter that has no decomposition.
+#[cfg(feature = "unicode")]
+fn transliterate(c: char) -> Option<&'static str> {
+    let part = match c {
+        '\u{DF}' | '\u{1E9E}' => "ss",
+        '\u{C6}' | '\u{E6}' => "ae",
+        '\u{D0}' | '\u{F0}' | '\u{110}' | '\u{111}' => "d",
+        '\u{D8}' | '\u{F8}' => "o",
+        '\u{DE}' | '\u{FE}' => "th",
+        '\u{126}' | '\u{127}' => "h",
+        '\u{131}' => "i",
+        '\u{138}' => "k",
+        '\u{13F}' | '\u{140}' | '\u{141}' | '\u{142}' => "l",
+        '\u{14A}' | '\u{14B}' => "ng",
+        '\u{152}' | '\u{153}' => "oe",
+        '\u{166}' | '\u{167}' => "t",
+        _ => return None,
+    };
+
+    Some(part)
+}
+
+/// [`Validator`] checks if a URL slug, separated by dashes.
+///
+/// A slug is one or more words of lowercase ASCII alphanumeric characters,
+/// separated by a single separator.
+///
+/// See [`slug_with`] to configure the separator and maximum length.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(slug))]
+/// pub struct Slug(String);
+///
+/// // Successfully constructed because the string is a slug.
+/// assert!(Slug::try_new("creme-brulee-recipe").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not slugs.
+/// assert!(Slug::try_new("Creme-Brulee").is_err());
+/// assert!(Slug::try_new("creme--brulee").is_err());
+/// assert!(Slug::try_new("-creme-brulee").is_err());
+/// assert!(Slug::try_new("").is_err());
+/// ```
+pub struct slug;
+
+impl<T> Validator<T> for slug
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        slug_with {
+            separator: '-',
+            max_length: None,
+        }
+        .validate(target)
+    }
+}
+
+/// [`Validator`] checks if a URL slug, with the given separator and optional
+/// maximum length.
+///
+/// See [`slug`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(slug_with { separator: '_', max_length: Some(16) }))]
+/// pub struct Slug(String);
+///
+/// // Successfully constructed because the string is a slug.
+/// assert!(Slug::try_new("seven_tea_inc").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not slugs.
+/// assert!(Slug::try_new("seven-tea-inc").is_err());
+/// assert!(Slug::try_new("seven_tea_inc_annual").is_err());
+/// ```
+pub struct slug_with {
+    pub separator: char,
+    pub max_length: Option<usize>,
+}
+
+impl<T> Validator<T> for slug_with
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+
+        if self
+            .max_length
+            .is_some_and(|max_length| target.len() > max_length)
+        {
+            return false;
+        }
+
+        target.split(self.separator).all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+    }
+}
+
+/// [`Sanitizer`] removes invisible characters.
+///
+/// Invisible characters include zero-width characters (e.g. U+200B and the
+/// zero-width joiner U+200D), the soft hyphen (U+00AD), bidirectional control
+/// characters (see [`strip_bidi_controls`]), variation selectors, tag
+/// characters, fillers, and other default ignorable code points. These can be
+/// used to make visually identical strings differ.
+///
+/// Characters can be kept with [`strip_invisible::allow`].
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(strip_invisible))]
+/// pub struct DisplayName(String);
+///
+/// // Removes the zero-width space and soft hyphen.
+/// assert_eq!(
+///     DisplayName::try_new("Sev\u{200B}en\u{AD}ty").unwrap().into_inner(),
+///     "Seventy"
+/// );
+/// ```
+pub struct strip_invisible;
+
+impl strip_invisible {
+    /// Keeps the allowed characters.
+    ///
+    /// # Examples
+    ///
+    /// The allowed characters are kept anywhere. To keep the zero-width joiner
+    /// and emoji variation selector only inside emoji sequences, see
+    /// [`strip_invisible::allow_in_emoji`].
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(strip_invisible.allow(['\u{200D}', '\u{FE0F}'])))]
+    /// pub struct DisplayName(String);
+    ///
+    /// // Keeps the zero-width joiners in the emoji, but removes the zero-width space.
+    /// assert_eq!(
+    ///     DisplayName::try_new("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200B}")
+    ///         .unwrap()
+    ///         .into_inner(),
+    ///     "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"
+    /// );
+    /// ```
+    pub fn allow<C>(self, allowed: C) -> allowing<Self, C> {
+        allowing(self, allowed)
+    }
+
+    /// Keeps the zero-width joiner (U+200D) and emoji variation selector
+    /// (U+FE0F) inside emoji sequences.
+    ///
+    /// A zero-width joiner is kept if it joins two emoji, and an emoji
+    /// variation selector is kept if it follows an emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(strip_invisible.allow_in_emoji()))]
+    /// pub struct DisplayName(String);
+    ///
+    /// // Keeps the zero-width joiners and variation selector in the emoji.
+    /// assert_eq!(
+    ///     DisplayName::try_new("\u{2764}\u{FE0F}\u{200D}\u{1F525}\u{1F468}\u{200D}\u{1F469}")
+    ///         .unwrap()
+    ///         .into_inner(),
+    ///     "\u{2764}\u{FE0F}\u{200D}\u{1F525}\u{1F468}\u{200D}\u{1F469}"
+    /// );
+    ///
+    /// // Removes the zero-width joiner between letters.
+    /// assert_eq!(
+    ///     DisplayName::try_new("Sev\u{200D}enty").unwrap().into_inner(),
+    ///     "Seventy"
+    /// );
+    /// ```
+    pub fn allow_in_emoji(self) -> allowing_in_emoji<Self> {
+        allowing_in_emoji(self)
+    }
+}
+
+impl<T> Sanitizer<T> for strip_invisible
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| target.retain(|c| !is_invisible(c)));
+    }
+}
+
+impl<T, C> Sanitizer<T> for allowing<strip_invisible, C>
+where
+    T: OwnedString,
+    C: AsRef<[char]>,
+{
+    fn sanitize(&self, target: &mut T) {
+        let allowed = self.1.as_ref();
+        target.with_string(|target| target.retain(|c| !is_invisible(c) || allowed.contains(&c)));
+    }
+}
+
+impl<T> Sanitizer<T> for allowing_in_emoji<strip_invisible>
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| {
+            *target = in_emoji(target)
+                .filter(|(c, in_emoji)| !is_invisible(*c) || *in_emoji)
+                .map(|(c, _)| c)
+                .collect();
+        });
+    }
+}
+
+/// [`Validator`] checks if contains no invisible characters.
+///
+/// See [`strip_invisible`] for which characters are invisible. Characters can
+/// be allowed with [`no_invisible::allow`].
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(no_invisible))]
+/// pub struct FileName(String);
+///
+/// // Successfully constructed because there are no invisible characters.
+/// assert!(FileName::try_new("seventy.txt").is_ok());
+///
+/// // Unsuccessfully constructed because there is a zero-width joiner.
+/// assert!(FileName::try_new("seven\u{200D}ty.txt").is_err());
+/// ```
+pub struct no_invisible;
+
+impl no_invisible {
+    /// Allows the given characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(validate(no_invisible.allow(['\u{200C}'])))]
+    /// pub struct PersianName(String);
+    ///
+    /// // Successfully constructed because the zero-width non-joiner is allowed.
+    /// assert!(PersianName::try_new("\u{645}\u{6CC}\u{200C}\u{62E}\u{648}\u{627}\u{647}\u{645}").is_ok());
+    ///
+    /// // Unsuccessfully constructed because the zero-width space is not allowed.
+    /// assert!(PersianName::try_new("\u{645}\u{6CC}\u{200B}").is_err());
+    /// ```
+    pub fn allow<C>(self, allowed: C) -> allowing<Self, C> {
+        allowing(self, allowed)
+    }
+
+    /// Allows the zero-width joiner (U+200D) and emoji variation selector
+    /// (U+FE0F) inside emoji sequences.
+    ///
+    /// See [`strip_invisible::allow_in_emoji`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(validate(no_invisible.allow_in_emoji()))]
+    /// pub struct Status(String);
+    ///
+    /// // Successfully constructed because the zero-width joiners are in the emoji.
+    /// assert!(Status::try_new("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}").is_ok());
+    ///
+    /// // Unsuccessfully constructed because the zero-width joiner is between letters.
+    /// assert!(Status::try_new("Sev\u{200D}enty").is_err());
+    /// ```
+    pub fn allow_in_emoji(self) -> allowing_in_emoji<Self> {
+        allowing_in_emoji(self)
+    }
+}
+
+impl<T> Validator<T> for no_invisible
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        !target.as_ref().chars().any(is_invisible)
+    }
+}
+
+impl<T, C> Validator<T> for allowing<no_invisible, C>
+where
+    T: AsRef<str>,
+    C: AsRef<[char]>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let allowed = self.1.as_ref();
+        !target
+            .as_ref()
+            .chars()
+            .any(|c| is_invisible(c) && !allowed.contains(&c))
+    }
+}
+
+impl<T> Validator<T> for allowing_in_emoji<no_invisible>
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        in_emoji(target.as_ref()).all(|(c, in_emoji)| !is_invisible(c) || in_emoji)
+    }
+}
+
+/// [`Sanitizer`] removes bidirectional control characters.
+///
+/// Bidirectional control characters change the display order of text. For
+/// example, the right-to-left override (U+202E) can make `exe.txt` display as
+/// `txt.exe`. The removed characters are U+061C, U+200E, U+200F, U+202A to
+/// U+202E, and U+2066 to U+2069.
+///
+/// Characters can be kept with [`strip_bidi_controls::allow`].
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(strip_bidi_controls))]
+/// pub struct FileName(String);
+///
+/// // Removes the right-to-left override.
+/// assert_eq!(
+///     FileName::try_new("seventy\u{202E}txt.exe").unwrap().into_inner(),
+///     "seventytxt.exe"
+/// );
+/// ```
+pub struct strip_bidi_controls;
+
+impl strip_bidi_controls {
+    /// Keeps the allowed characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(strip_bidi_controls.allow(['\u{200E}', '\u{200F}'])))]
+    /// pub struct Message(String);
+    ///
+    /// // Keeps the right-to-left mark, but removes the right-to-left override.
+    /// assert_eq!(
+    ///     Message::try_new("\u{200F}seventy\u{202E}").unwrap().into_inner(),
+    ///     "\u{200F}seventy"
+    /// );
+    /// ```
+    pub fn allow<C>(self, allowed: C) -> allowing<Self, C> {
+        allowing(self, allowed)
+    }
+}
+
+impl<T> Sanitizer<T> for strip_bidi_controls
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| target.retain(|c| !is_bidi_control(c)));
+    }
+}
+
+impl<T, C> Sanitizer<T> for allowing<strip_bidi_controls, C>
+where
+    T: OwnedString,
+    C: AsRef<[char]>,
+{
+    fn sanitize(&self, target: &mut T) {
+        let allowed = self.1.as_ref();
+        target.with_string(|target| target.retain(|c| !is_bidi_control(c) || allowed.contains(&c)));
+    }
+}
+
+/// [`Validator`] checks if contains no bidirectional control characters.
+///
+/// See [`strip_bidi_controls`] for which characters are bidirectional control
+/// characters. Characters can be allowed with [`no_bidi_controls::allow`].
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(no_bidi_controls))]
+/// pub struct FileName(String);
+///
+/// // Successfully constructed because there are no bidirectional control characters.
+/// assert!(FileName::try_new("seventy.txt").is_ok());
+///
+/// // Unsuccessfully constructed because there is a right-to-left override.
+/// assert!(FileName::try_new("seventy\u{202E}txt.exe").is_err());
+/// ```
+pub struct no_bidi_controls;
+
+impl no_bidi_controls {
+    /// Allows the given characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(validate(no_bidi_controls.allow(['\u{200E}', '\u{200F}'])))]
+    /// pub struct Message(String);
+    ///
+    /// // Successfully constructed because the right-to-left mark is allowed.
+    /// assert!(Message::try_new("\u{200F}seventy").is_ok());
+    ///
+    /// // Unsuccessfully constructed because the right-to-left override is not allowed.
+    /// assert!(Message::try_new("seventy\u{202E}").is_err());
+    /// ```
+    pub fn allow<C>(self, allowed: C) -> allowing<Self, C> {
+        allowing(self, allowed)
+    }
+}
+
+impl<T> Validator<T> for no_bidi_controls
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        !target.as_ref().chars().any(is_bidi_control)
+    }
+}
+
+impl<T, C> Validator<T> for allowing<no_bidi_controls, C>
+where
+    T: AsRef<str>,
+    C: AsRef<[char]>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let allowed = self.1.as_ref();
+        !target
+            .as_ref()
+            .chars()
+            .any(|c| is_bidi_control(c) && !allowed.contains(&c))
+    }
+}
+
+/// [`Sanitizer`] removes control characters.
+///
+/// Control characters are those in the Unicode `Cc` category, such as NUL,
+/// `\t`, `\n`, and ESC. Characters can be kept with
+/// [`strip_control_chars::allow`].
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(strip_control_chars))]
+/// pub struct Title(String);
+///
+/// // Removes the control characters.
+/// assert_eq!(
+///     Title::try_new("\u{1B}[31mSeventy\u{0}\n").unwrap().into_inner(),
+///     "[31mSeventy"
+/// );
+/// ```
+pub struct strip_control_chars;
+
+impl strip_control_chars {
+    /// Keeps the allowed characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(strip_control_chars.allow(['\n', '\t'])))]
+    /// pub struct Description(String);
+    ///
+    /// // Keeps the newline, but removes the NUL.
+    /// assert_eq!(
+    ///     Description::try_new("Seven\u{0}\nTea").unwrap().into_inner(),
+    ///     "Seven\nTea"
+    /// );
+    /// ```
+    pub fn allow<C>(self, allowed: C) -> allowing<Self, C> {
+        allowing(self, allowed)
+    }
+}
+
+impl<T> Sanitizer<T> for strip_control_chars
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| target.retain(|c| !c.is_control()));
+    }
+}
+
+impl<T, C> Sanitizer<T> for allowing<strip_control_chars, C>
+where
+    T: OwnedString,
+    C: AsRef<[char]>,
+{
+    fn sanitize(&self, target: &mut T) {
+        let allowed = self.1.as_ref();
+        target.with_string(|target| target.retain(|c| !c.is_control() || allowed.contains(&c)));
+    }
+}
+
+/// [`Sanitizer`] or [`Validator`] with an allow-list of characters.
+///
+/// Constructed with the `allow` methods, such as [`strip_invisible::allow`].
+pub struct allowing<SV, C>(pub SV, pub C);
+
+/// [`Sanitizer`] or [`Validator`] that allows joiners inside emoji sequences.
+///
+/// Constructed with the `allow_in_emoji` methods, such as
+/// [`strip_invisible::allow_in_emoji`].
+pub struct allowing_in_emoji<SV>(pub SV);
+
+/// Checks if the character is a bidirectional control character.
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Checks if the character is invisible.
+fn is_invisible(c: char) -> bool {
+    is_bidi_control(c)
+        || matches!(
+            c,
+            '\u{00AD}'
+                | '\u{034F}'
+                | '\u{115F}'
+                | '\u{1160}'
+                | '\u{17B4}'
+                | '\u{17B5}'
+                | '\u{180B}'..='\u{180F}'
+                | '\u{200B}'..='\u{200D}'
+                | '\u{2060}'..='\u{2065}'
+                | '\u{206A}'..='\u{206F}'
+                | '\u{3164}'
+                | '\u{FE00}'..='\u{FE0F}'
+                | '\u{FEFF}'
+                | '\u{FFA0}'
+                | '\u{1D173}'..='\u{1D17A}'
+                | '\u{E0000}'..='\u{E0FFF}'
+        )
+}
+
+/// Pairs each character with whether it is a zero-width joiner or emoji
+/// variation selector inside an emoji sequence.
+fn in_emoji(target: &str) -> impl Iterator<Item = (char, bool)> + '_ {
+    let mut chars = target.chars().peekable();
+    let mut after_emoji = false;
+
+    std::iter::from_fn(move || {
+        let c = chars.next()?;
+
+        let in_emoji = match c {
+            '\u{200D}' => after_emoji && chars.peek().is_some_and(|next| is_emoji(*next)),
+            '\u{FE0F}' => after_emoji,
+            _ => false,
+        };
+
+        after_emoji = is_emoji(c) || (c == '\u{FE0F}' && in_emoji);
+
+        Some((c, in_emoji))
+    })
+}
+
+/// Checks if the character is an emoji, including skin tone modifiers.
+///
+/// Approximates the `Extended_Pictographic` property.
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00A9}'
+            | '\u{00AE}'
+            | '\u{203C}'
+            | '\u{2049}'
+            | '\u{2122}'
+            | '\u{2139}'
+            | '\u{2194}'..='\u{2199}'
+            | '\u{21A9}'..='\u{21AA}'
+            | '\u{231A}'..='\u{231B}'
+            | '\u{2328}'
+            | '\u{23CF}'
+            | '\u{23E9}'..='\u{23FA}'
+            | '\u{24C2}'
+            | '\u{25AA}'..='\u{25AB}'
+            | '\u{25B6}'
+            | '\u{25C0}'
+            | '\u{25FB}'..='\u{25FE}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2934}'..='\u{2935}'
+            | '\u{2B05}'..='\u{2B07}'
+            | '\u{2B1B}'..='\u{2B1C}'
+            | '\u{2B50}'
+            | '\u{2B55}'
+            | '\u{3030}'
+            | '\u{303D}'
+            | '\u{3297}'
+            | '\u{3299}'
+            | '\u{1F000}'..='\u{1FAFF}'
+            | '\u{1FC00}'..='\u{1FFFD}'
+    )
+}
+
+/// [`Validator`] checks if only alphabetic.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(alphabetic))]
+/// pub struct FirstName(String);
+///
+/// // Successfully constructed because the string is only alphabetic.
+/// assert!(FirstName::try_new("Michael").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not only alphabetic.
+/// assert!(FirstName::try_new("Mich4el").is_err());
+/// ```
+pub struct alphabetic;
+
+impl<T> Validator<T> for alphabetic
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().all(char::is_alphabetic)
+    }
+}
+
+/// [`Validator`] checks if only alphanumeric.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(alphanumeric))]
+/// pub struct Username(String);
+///
+/// // Successfully constructed because the string is only alphanumeric.
+/// assert!(Username::try_new("Seventy70").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not only alphanumeric.
+/// assert!(Username::try_new("Seventy#70!").is_err());
+/// ```
+pub struct alphanumeric;
+
+impl<T> Validator<T> for alphanumeric
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().all(char::is_alphanumeric)
+    }
+}
+
+/// [`Validator`] checks if only ASCII.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(ascii))]
+/// pub struct Password(String);
+///
+/// // Successfully constructed because the string is only ASCII.
+/// assert!(Password::try_new("Seventy#70!").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not only ASCII.
+/// assert!(Password::try_new("Seventy\u{7070}#70!").is_err());
+/// ```
+pub struct ascii;
+
+impl<T> Validator<T> for ascii
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().is_ascii()
+    }
+}
+
+/// [`Sanitizer`] converts to lowercase. [`Validator`] checks if only lowercase.
+///
+/// # Examples
+///
+/// An example of the `lowercase` validator.
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(lowercase))]
+/// pub struct SearchQuery(String);
+///
+/// // Converts uppercase characters to lowercase.
+/// assert_eq!(
+///     SearchQuery::try_new("What is the SeVeNTy crate?!")
+///         .unwrap()
+///         .into_inner(),
+///     "what is the seventy crate?!"
+/// );
+/// ```
+///
+/// An example of the `lowercase` sanitizer.
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(lowercase))]
+/// pub struct LowercaseString(String);
+///
+/// // Successfully constructed because the string is only lowercase.
+/// assert!(LowercaseString::try_new("whisper").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not only lowercase.
+/// assert!(LowercaseString::try_new("Whisper").is_err());
+/// ```
+pub struct lowercase;
+
+impl<T> Sanitizer<T> for lowercase
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| *target = target.to_lowercase());
+    }
+}
+
+impl<T> Validator<T> for lowercase
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().all(char::is_lowercase)
+    }
+}
+
+/// [`Sanitizer`] converts to uppercase. [`Validator`] checks if only uppercase.
+///
+/// # Examples
+///
+/// An example of the `uppercase` sanitizer.
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(uppercase))]
+/// pub struct ID(String);
+///
+/// // Converts lowercase characters to uppercase.
+/// assert_eq!(
+///     ID::try_new("70sV-Nty70").unwrap().into_inner(),
+///     "70SV-NTY70"
+/// );
+/// ```
+///
+/// An example of the `uppercase` validator.
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(uppercase))]
+/// pub struct UppercaseString(String);
+///
+/// // Successfully constructed because the string is only uppercase.
+/// assert!(UppercaseString::try_new("SHOUT").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not only uppercase.
+/// assert!(UppercaseString::try_new("Shout").is_err());
+/// ```
+pub struct uppercase;
+
+impl<T> Sanitizer<T> for uppercase
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| *target = target.to_uppercase());
+    }
+}
+
+impl<T> Validator<T> for uppercase
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().all(char::is_uppercase)
+    }
+}
+
+/// [`Validator`] forwards length to inner validator.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{compare::*, string::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(length::chars(le(15))))]
+/// pub struct TextBox(String);
+///
+/// // Successfully constructed because the string is <= 15 characters.
+/// assert!(TextBox::try_new("Hello, World!").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not <= 15 characters.
+/// assert!(TextBox::try_new("Hello, World! I am hungry.").is_err());
+/// ```
+#[non_exhaustive]
+pub enum length<V> {
+    bytes(V),
+    chars(V),
+    /// Counts user-perceived characters.
+    ///
+    /// ```
+    /// use seventy::{
+    ///     builtins::{compare::*, string::*},
+    ///     seventy, Newtype,
+    /// };
+    ///
+    /// #[seventy(validate(length::graphemes(within(1..=2))))]
+    /// pub struct Reaction(String);
+    ///
+    /// // Successfully constructed because the family emoji is a single grapheme.
+    /// assert!(Reaction::try_new("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}").is_ok());
+    ///
+    /// // Unsuccessfully constructed because the string is 3 graphemes.
+    /// assert!(Reaction::try_new("\u{1F44D}\u{1F44D}\u{1F44D}").is_err());
+    /// ```
+    #[cfg(feature = "unicode")]
+    graphemes(V),
+    /// Counts terminal columns, with East Asian wide characters counted as 2.
+    ///
+    /// ```
+    /// use seventy::{
+    ///     builtins::{compare::*, string::*},
+    ///     seventy, Newtype,
+    /// };
+    ///
+    /// #[seventy(validate(length::width(le(4))))]
+    /// pub struct StatusColumn(String);
+    ///
+    /// // Successfully constructed because the string is 4 columns wide.
+    /// assert!(StatusColumn::try_new("\u{7070}\u{7070}").is_ok());
+    ///
+    /// // Unsuccessfully constructed because the string is 6 columns wide.
+    /// assert!(StatusColumn::try_new("\u{7070}\u{7070}\u{7070}").is_err());
+    /// ```
+    #[cfg(feature = "unicode")]
+    width(V),
+}
+
+impl<T, V> Validator<T> for length<V>
+where
+    T: AsRef<str>,
+    V: Validator<usize>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+
+        match self {
+            Self::bytes(v) => v.validate(&target.len()),
+            Self::chars(v) => v.validate(&target.chars().count()),
+            #[cfg(feature = "unicode")]
+            Self::graphemes(v) => {
+                use unicode_segmentation_util::UnicodeSegmentation;
+
+                v.validate(&target.graphemes(true).count())
+            }
+            #[cfg(feature = "unicode")]
+            Self::width(v) => {
+                use unicode_width_util::UnicodeWidthStr;
+
+                v.validate(&target.width())
+            }
+        }
+    }
+}
+
+/// [`Validator`] checks if not empty.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(not_empty))]
+/// pub struct TextBox(String);
+///
+/// // Successfully constructed because the string is not empty.
+/// assert!(TextBox::try_new("Hello, World!").is_ok());
+///
+/// // Unsuccessfully constructed because the string is empty.
+/// assert!(TextBox::try_new("").is_err());
+/// ```
+pub struct not_empty;
+
+impl<T> Validator<T> for not_empty
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        !target.as_ref().is_empty()
+    }
+}
+
+/// [`Validator`] forwards word count to inner validator.
+///
+/// Words are found with the Unicode word boundary rules, ignoring whitespace
+/// and punctuation. Requires the `unicode` feature.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{compare::*, string::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(words(le(5))))]
+/// pub struct Bio(String);
+///
+/// // Successfully constructed because the string is <= 5 words.
+/// assert!(Bio::try_new("I can't stop drinking tea.").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not <= 5 words.
+/// assert!(Bio::try_new("I can't stop drinking tea, send help!").is_err());
+/// ```
+#[cfg(feature = "unicode")]
+pub struct words<V>(pub V);
+
+#[cfg(feature = "unicode")]
+impl<T, V> Validator<T> for words<V>
+where
+    T: AsRef<str>,
+    V: Validator<usize>,
+{
+    fn validate(&self, target: &T) -> bool {
+        use unicode_segmentation_util::UnicodeSegmentation;
+
+        self.0.validate(&target.as_ref().unicode_words().count())
+    }
+}
+
+/// [`Validator`] forwards line count to inner validator.
+///
+/// Lines are separated by `\r\n`, `\r`, or `\n`. A trailing line ending
+/// doesn't start another line, and an empty string has no lines.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{compare::*, string::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(lines(le(3))))]
+/// pub struct Address(String);
+///
+/// // Successfully constructed because the string is <= 3 lines.
+/// assert!(Address::try_new("70 Seventy St\nSeventyville\n").is_ok());
+///
+/// // Unsuccessfully constructed because the strings are not <= 3 lines.
+/// assert!(Address::try_new("Apt 7\n70 Seventy St\nSeventyville\nSeventyland").is_err());
+/// assert!(Address::try_new("Apt 7\r70 Seventy St\rSeventyville\rSeventyland").is_err());
+/// ```
+pub struct lines<V>(pub V);
+
+impl<T, V> Validator<T> for lines<V>
+where
+    T: AsRef<str>,
+    V: Validator<usize>,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.0.validate(&split_lines(target.as_ref()).count())
+    }
+}
+
+/// [`Validator`] forwards each line to inner validator, checking if all are
+/// valid.
+///
+/// Lines are separated by `\r\n`, `\r`, or `\n`, and don't include the line
+/// ending.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{compare::*, string::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(each_line(length::chars(le(20)))))]
+/// pub struct CommitMessage(String);
+///
+/// // Successfully constructed because each line is <= 20 characters.
+/// assert!(CommitMessage::try_new("Add seventy\n\nIt is cool.").is_ok());
+///
+/// // Unsuccessfully constructed because a line is not <= 20 characters.
+/// assert!(CommitMessage::try_new("Add seventy\n\nIt is very, very cool.").is_err());
+/// ```
+pub struct each_line<V>(pub V);
+
+impl<T, V> Validator<T> for each_line<V>
+where
+    T: AsRef<str>,
+    V: for<'a> Validator<&'a str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        split_lines(target.as_ref()).all(|line| self.0.validate(&line))
+    }
+}
+
+/// Splits the string into lines, separated by `\r\n`, `\r`, or `\n`.
+fn split_lines(target: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(target).filter(|rest| !rest.is_empty());
+
+    std::iter::from_fn(move || {
+        let current = rest?;
+
+        let Some(index) = current.find(['\r', '\n']) else {
+            rest = None;
+            return Some(current);
+        };
+
+        let ending = if current[index..].starts_with("\r\n") {
+            2
+        } else {
+            1
+        };
+        rest = Some(&current[index + ending..]).filter(|rest| !rest.is_empty());
+
+        Some(&current[..index])
+    })
+}
+
+/// [`Validator`] forwards each character to inner validator, checking if all
+/// are valid.
+///
+/// An empty string is valid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{char::*, collection::*, operator::*, string::chars_all},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(chars_all(any!(alphanumeric, among(['_', '-'])))))]
+/// pub struct Username(String);
+///
+/// // Successfully constructed because all characters are alphanumeric, '_' or '-'.
+/// assert!(Username::try_new("seventy_70-tea").is_ok());
+///
+/// // Unsuccessfully constructed because '$' is not alphanumeric, '_' or '-'.
+/// assert!(Username::try_new("seventy$70").is_err());
+/// ```
+pub struct chars_all<V>(pub V);
+
+impl<T, V> Validator<T> for chars_all<V>
+where
+    T: AsRef<str>,
+    V: Validator<char>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().all(|c| self.0.validate(&c))
+    }
+}
+
+/// [`Validator`] forwards each character to inner validator, checking if any
+/// are valid.
+///
+/// An empty string is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{char::*, string::chars_any},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(chars_any(uppercase)))]
+/// pub struct Password(String);
+///
+/// // Successfully constructed because 'S' is uppercase.
+/// assert!(Password::try_new("Seventy70").is_ok());
+///
+/// // Unsuccessfully constructed because no character is uppercase.
+/// assert!(Password::try_new("seventy70").is_err());
+/// ```
+pub struct chars_any<V>(pub V);
+
+impl<T, V> Validator<T> for chars_any<V>
+where
+    T: AsRef<str>,
+    V: Validator<char>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().chars().any(|c| self.0.validate(&c))
+    }
+}
+
+/// [`Validator`] forwards each character to inner validator, checking if none
+/// are valid.
+///
+/// An empty string is valid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{collection::*, string::chars_none},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(chars_none(among(['<', '>', '&']))))]
+/// pub struct Nickname(String);
+///
+/// // Successfully constructed because no character is '<', '>' or '&'.
+/// assert!(Nickname::try_new("Seventy").is_ok());
+///
+/// // Unsuccessfully constructed because '<' and '>' are present.
+/// assert!(Nickname::try_new("<Seventy>").is_err());
+/// ```
+pub struct chars_none<V>(pub V);
+
+impl<T, V> Validator<T> for chars_none<V>
+where
+    T: AsRef<str>,
+    V: Validator<char>,
+{
+    fn validate(&self, target: &T) -> bool {
+        !target.as_ref().chars().any(|c| self.0.validate(&c))
+    }
+}
+
+/// [`Validator`] forwards the first character to inner validator.
+///
+/// An empty string is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{char::*, string::first_char},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(first_char(alphabetic)))]
+/// pub struct Identifier(String);
+///
+/// // Successfully constructed because 's' is alphabetic.
+/// assert!(Identifier::try_new("seventy70").is_ok());
+///
+/// // Unsuccessfully constructed because '7' is not alphabetic.
+/// assert!(Identifier::try_new("70seventy").is_err());
+///
+/// // Unsuccessfully constructed because there is no first character.
+/// assert!(Identifier::try_new("").is_err());
+/// ```
+pub struct first_char<V>(pub V);
+
+impl<T, V> Validator<T> for first_char<V>
+where
+    T: AsRef<str>,
+    V: Validator<char>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target
+            .as_ref()
+            .chars()
+            .next()
+            .is_some_and(|c| self.0.validate(&c))
+    }
+}
+
+/// [`Validator`] forwards the last character to inner validator.
+///
+/// An empty string is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{collection::*, string::last_char},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(last_char(among(['.', '!', '?']))))]
+/// pub struct Sentence(String);
+///
+/// // Successfully constructed because '!' is among the given array.
+/// assert!(Sentence::try_new("Seventy is a cool crate!").is_ok());
+///
+/// // Unsuccessfully constructed because 'e' is not among the given array.
+/// assert!(Sentence::try_new("Seventy is a cool crate").is_err());
+/// ```
+pub struct last_char<V>(pub V);
+
+impl<T, V> Validator<T> for last_char<V>
+where
+    T: AsRef<str>,
+    V: Validator<char>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target
+            .as_ref()
+            .chars()
+            .next_back()
+            .is_some_and(|c| self.0.validate(&c))
+    }
+}
+
+/// Pattern searched for by the substring [`Validator`]s, such as
+/// [`starts_with`] and [`contains`].
+///
+/// Implemented for any [`AsRef<str>`], which matches case-sensitively.
+/// Wrap the pattern in [`ignore_ascii_case`] or [`ignore_case`] to match
+/// case-insensitively.
+pub trait SubstringPattern {
+    /// Checks if the pattern is a prefix of the haystack.
+    fn is_prefix_of(&self, haystack: &str) -> bool;
+
+    /// Checks if the pattern is a suffix of the haystack.
+    fn is_suffix_of(&self, haystack: &str) -> bool;
+
+    /// Checks if the pattern is contained in the haystack.
+    fn is_contained_in(&self, haystack: &str) -> bool;
+}
+
+impl<P> SubstringPattern for P
+where
+    P: AsRef<str>,
+{
+    fn is_prefix_of(&self, haystack: &str) -> bool {
+        haystack.starts_with(self.as_ref())
+    }
+
+    fn is_suffix_of(&self, haystack: &str) -> bool {
+        haystack.ends_with(self.as_ref())
+    }
+
+    fn is_contained_in(&self, haystack: &str) -> bool {
+        haystack.contains(self.as_ref())
+    }
+}
+
+/// [`SubstringPattern`] that matches ignoring ASCII case.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(ends_with(ignore_ascii_case(".internal"))))]
+/// pub struct InternalHost(String);
+///
+/// // Successfully constructed because the suffix matches, ignoring ASCII case.
+/// assert!(InternalHost::try_new("billing.INTERNAL").is_ok());
+///
+/// // Unsuccessfully constructed because the suffix doesn't match.
+/// assert!(InternalHost::try_new("billing.example").is_err());
+/// ```
+pub struct ignore_ascii_case<P>(pub P);
+
+impl<P> SubstringPattern for ignore_ascii_case<P>
+where
+    P: AsRef<str>,
+{
+    fn is_prefix_of(&self, haystack: &str) -> bool {
+        let pattern = self.0.as_ref().as_bytes();
+
+        haystack
+            .as_bytes()
+            .get(..pattern.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(pattern))
+    }
+
+    fn is_suffix_of(&self, haystack: &str) -> bool {
+        let pattern = self.0.as_ref().as_bytes();
+        let haystack = haystack.as_bytes();
+
+        haystack
+            .len()
+            .checked_sub(pattern.len())
+            .is_some_and(|start| haystack[start..].eq_ignore_ascii_case(pattern))
+    }
+
+    fn is_contained_in(&self, haystack: &str) -> bool {
+        let pattern = self.0.as_ref().as_bytes();
+
+        pattern.is_empty()
+            || haystack
+                .as_bytes()
+                .windows(pattern.len())
+                .any(|window| window.eq_ignore_ascii_case(pattern))
+    }
+}
+
+/// [`SubstringPattern`] that matches ignoring case.
+///
+/// Both the pattern and the haystack are converted to lowercase before
+/// matching.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(not_contains(ignore_case("\u{3A3}\u{395}\u{392}\u{395}\u{39D}"))))]
+/// pub struct Comment(String);
+///
+/// // Successfully constructed because the pattern is not contained.
+/// assert!(Comment::try_new("Seventy").is_ok());
+///
+/// // Unsuccessfully constructed because the pattern is contained, ignoring case.
+/// assert!(Comment::try_new("\u{3C3}\u{3B5}\u{3B2}\u{3B5}\u{3BD}").is_err());
+/// ```
+pub struct ignore_case<P>(pub P);
+
+impl<P> SubstringPattern for ignore_case<P>
+where
+    P: AsRef<str>,
+{
+    fn is_prefix_of(&self, haystack: &str) -> bool {
+        haystack
+            .to_lowercase()
+            .starts_with(&self.0.as_ref().to_lowercase())
+    }
+
+    fn is_suffix_of(&self, haystack: &str) -> bool {
+        haystack
+            .to_lowercase()
+            .ends_with(&self.0.as_ref().to_lowercase())
+    }
+
+    fn is_contained_in(&self, haystack: &str) -> bool {
+        haystack
+            .to_lowercase()
+            .contains(&self.0.as_ref().to_lowercase())
+    }
+}
+
+/// [`Validator`] checks if starts with the pattern.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(starts_with("sk_live_")))]
+/// pub struct LiveSecretKey(String);
+///
+/// // Successfully constructed because the string starts with "sk_live_".
+/// assert!(LiveSecretKey::try_new("sk_live_7070").is_ok());
+///
+/// // Unsuccessfully constructed because the string doesn't start with "sk_live_".
+/// assert!(LiveSecretKey::try_new("sk_test_7070").is_err());
+/// ```
+pub struct starts_with<P>(pub P);
+
+impl<T, P> Validator<T> for starts_with<P>
+where
+    T: AsRef<str>,
+    P: SubstringPattern,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.0.is_prefix_of(target.as_ref())
+    }
+}
+
+/// [`Validator`] checks if starts with any of the patterns.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(starts_with_any([
+///     ignore_ascii_case("http://"),
+///     ignore_ascii_case("https://"),
+/// ])))]
+/// pub struct WebAddress(String);
+///
+/// // Successfully constructed because the string starts with "https://".
+/// assert!(WebAddress::try_new("HTTPS://example.com").is_ok());
+///
+/// // Unsuccessfully constructed because the string doesn't start with either
+/// // pattern.
+/// assert!(WebAddress::try_new("ftp://example.com").is_err());
+/// ```
+pub struct starts_with_any<C>(pub C);
+
+impl<T, P, const N: usize> Validator<T> for starts_with_any<[P; N]>
+where
+    T: AsRef<str>,
+    P: SubstringPattern,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+        self.0.iter().any(|pattern| pattern.is_prefix_of(target))
+    }
+}
+
+/// [`Validator`] checks if ends with the pattern.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(ends_with(".internal")))]
+/// pub struct InternalHost(String);
+///
+/// // Successfully constructed because the string ends with ".internal".
+/// assert!(InternalHost::try_new("billing.internal").is_ok());
+///
+/// // Unsuccessfully constructed because the string doesn't end with ".internal".
+/// assert!(InternalHost::try_new("billing.example.com").is_err());
+/// ```
+pub struct ends_with<P>(pub P);
+
+impl<T, P> Validator<T> for ends_with<P>
+where
+    T: AsRef<str>,
+    P: SubstringPattern,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.0.is_suffix_of(target.as_ref())
+    }
+}
+
+/// [`Validator`] checks if ends with any of the patterns.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(ends_with_any([".png", ".jpg"])))]
+/// pub struct ImageFileName(String);
+///
+/// // Successfully constructed because the string ends with ".png".
+/// assert!(ImageFileName::try_new("seventy.png").is_ok());
+///
+/// // Unsuccessfully constructed because the string doesn't end with either
+/// // pattern.
+/// assert!(ImageFileName::try_new("seventy.exe").is_err());
+/// ```
+pub struct ends_with_any<C>(pub C);
+
+impl<T, P, const N: usize> Validator<T> for ends_with_any<[P; N]>
+where
+    T: AsRef<str>,
+    P: SubstringPattern,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+        self.0.iter().any(|pattern| pattern.is_suffix_of(target))
+    }
+}
+
+/// [`Validator`] checks if contains the pattern.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(contains("@")))]
+/// pub struct Mention(String);
+///
+/// // Successfully constructed because the string contains "@".
+/// assert!(Mention::try_new("Hi @seventy").is_ok());
+///
+/// // Unsuccessfully constructed because the string doesn't contain "@".
+/// assert!(Mention::try_new("Hi seventy").is_err());
+/// ```
+pub struct contains<P>(pub P);
+
+impl<T, P> Validator<T> for contains<P>
+where
+    T: AsRef<str>,
+    P: SubstringPattern,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.0.is_contained_in(target.as_ref())
+    }
+}
+
+/// [`Validator`] checks if contains any of the patterns.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(contains_any(["TODO", "FIXME"])))]
+/// pub struct PendingTask(String);
+///
+/// // Successfully constructed because the string contains "FIXME".
+/// assert!(PendingTask::try_new("FIXME: Optimize").is_ok());
+///
+/// // Unsuccessfully constructed because the string doesn't contain either
+/// // pattern.
+/// assert!(PendingTask::try_new("Optimize").is_err());
+/// ```
+pub struct contains_any<C>(pub C);
+
+impl<T, P, const N: usize> Validator<T> for contains_any<[P; N]>
+where
+    T: AsRef<str>,
+    P: SubstringPattern,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+        self.0.iter().any(|pattern| pattern.is_contained_in(target))
+    }
+}
+
+/// [`Validator`] checks if doesn't contain the pattern.
+///
+/// To check if none of multiple patterns are contained, use
+/// `not(contains_any([...]))`.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(not_contains(ignore_ascii_case("admin"))))]
+/// pub struct Username(String);
+///
+/// // Successfully constructed because the string doesn't contain "admin".
+/// assert!(Username::try_new("seventy").is_ok());
+///
+/// // Unsuccessfully constructed because the string contains "admin", ignoring
+/// // ASCII case.
+/// assert!(Username::try_new("SeventyAdmin").is_err());
+/// ```
+pub struct not_contains<P>(pub P);
+
+impl<T, P> Validator<T> for not_contains<P>
+where
+    T: AsRef<str>,
+    P: SubstringPattern,
+{
+    fn validate(&self, target: &T) -> bool {
+        !self.0.is_contained_in(target.as_ref())
+    }
+}
+
+/// [`Validator`] checks if matches regex.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(regex(r"^[A-Z]([a-zA-Z0-9]|[- @\.#&!])*$")))]
+/// pub struct Company(String);
+///
+/// // Successfully constructed because "Seven Tea Inc." matches the regex.
+/// assert!(Company::try_new("Seven Tea Inc.").is_ok());
+///
+/// // Unsuccessfuly constructed because "Seven \u{1F375} Inc." does not match the regex.
+/// assert!(Company::try_new("Seven \u{1F375} Inc.").is_err());
+/// ```
+#[cfg(feature = "regex")]
+pub fn regex(regex: &'static str) -> _regex {
+    _regex(regex_util::Regex::new(regex).unwrap())
+}
+
+#[doc(hidden)]
+#[cfg(feature = "regex")]
+pub struct _regex(regex_util::Regex);
+
+#[cfg(feature = "regex")]
+impl<T> Validator<T> for _regex
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.0.is_match(target.as_ref())
+    }
+}
+
+/// [`Validator`] checks if parses as the type.
+///
+/// Works for any type implementing [`FromStr`]. The string is kept as is,
+/// e.g. to preserve leading zeros.
+///
+/// Use `.trim()` to ignore surrounding whitespace, and `.thousands(separator)`
+/// to ignore a thousands separator. The separator must separate groups of 3
+/// digits, with 1 to 3 digits before the first separator.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(parses_as::<u32>()))]
+/// pub struct Quantity(String);
+///
+/// // Successfully constructed because the string parses as a `u32`.
+/// assert!(Quantity::try_new("0070").is_ok());
+///
+/// // Unsuccessfully constructed because the strings don't parse as a `u32`.
+/// assert!(Quantity::try_new("-70").is_err());
+/// assert!(Quantity::try_new("1,070").is_err());
+///
+/// #[seventy(validate(parses_as::<u32>().trim().thousands(',')))]
+/// pub struct CsvQuantity(String);
+///
+/// // Successfully constructed because the whitespace and separator are ignored.
+/// assert!(CsvQuantity::try_new(" 1,070 ").is_ok());
+///
+/// // Unsuccessfully constructed because the separators don't separate groups of 3 digits.
+/// assert!(CsvQuantity::try_new("1070,").is_err());
+/// assert!(CsvQuantity::try_new("1,5").is_err());
+/// assert!(CsvQuantity::try_new("1070,000").is_err());
+/// ```
+pub fn parses_as<T>() -> _parses_as<T>
+where
+    T: FromStr,
+{
+    _parses_as(ParseOptions::default(), PhantomData)
+}
+
+#[doc(hidden)]
+pub struct _parses_as<T>(ParseOptions, PhantomData<fn() -> T>);
+
+impl<T> _parses_as<T> {
+    /// Ignores surrounding whitespace.
+    pub fn trim(mut self) -> Self {
+        self.0.trim = true;
+        self
+    }
+
+    /// Ignores the thousands separator between groups of 3 digits.
+    pub fn thousands(mut self, separator: char) -> Self {
+        self.0.thousands = Some(separator);
+        self
+    }
+}
+
+impl<T, U> Validator<T> for _parses_as<U>
+where
+    T: AsRef<str>,
+    U: FromStr,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.0.parse::<U>(target.as_ref()).is_some()
+    }
+}
+
+/// [`Validator`] forwards the parsed value to inner validator.
+///
+/// Works for any type implementing [`FromStr`], and is invalid if the string
+/// doesn't parse. The string is kept as is.
+///
+/// Use `.trim()` to ignore surrounding whitespace, and `.thousands(separator)`
+/// to ignore a thousands separator. The separator must separate groups of 3
+/// digits, with 1 to 3 digits before the first separator.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{compare::*, string::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(parse_then::<u16>(within(1024..))))]
+/// pub struct Port(String);
+///
+/// // Successfully constructed because the string parses as a `u16` >= 1024.
+/// assert!(Port::try_new("7070").is_ok());
+///
+/// // Unsuccessfully constructed because the strings don't parse as a `u16`
+/// // >= 1024.
+/// assert!(Port::try_new("70").is_err());
+/// assert!(Port::try_new("70700").is_err());
+/// assert!(Port::try_new("seventy").is_err());
+///
+/// #[seventy(validate(parse_then::<f64>(within(0.0..=1_000_000.0)).trim().thousands(',')))]
+/// pub struct Price(String);
+///
+/// // Successfully constructed because the whitespace and separators are ignored.
+/// assert!(Price::try_new(" 7,070.70").is_ok());
+/// ```
+pub fn parse_then<T>(validator: impl Validator<T>) -> _parse_then<T, impl Validator<T>>
+where
+    T: FromStr,
+{
+    _parse_then(ParseOptions::default(), validator, PhantomData)
+}
+
+#[doc(hidden)]
+pub struct _parse_then<T, V>(ParseOptions, V, PhantomData<fn() -> T>);
+
+impl<T, V> _parse_then<T, V> {
+    /// Ignores surrounding whitespace.
+    pub fn trim(mut self) -> Self {
+        self.0.trim = true;
+        self
+    }
+
+    /// Ignores the thousands separator between groups of 3 digits.
+    pub fn thousands(mut self, separator: char) -> Self {
+        self.0.thousands = Some(separator);
+        self
+    }
+}
+
+impl<T, U, V> Validator<T> for _parse_then<U, V>
+where
+    T: AsRef<str>,
+    U: FromStr,
+    V: Validator<U>,
+{
+    fn validate(&self, target: &T) -> bool {
+        self.0
+            .parse::<U>(target.as_ref())
+            .is_some_and(|parsed| self.1.validate(&parsed))
+    }
+}
+
+/// Options for [`parses_as`] and [`parse_then`].
+#[derive(Default)]
+struct ParseOptions {
+    trim: bool,
+    thousands: Option<char>,
+}
+
+impl ParseOptions {
+    /// Parses the string with the options.
+    fn parse<T>(&self, target: &str) -> Option<T>
+    where
+        T: FromStr,
+    {
+        let target = if self.trim { target.trim() } else { target };
+
+        let Some(separator) = self
+            .thousands
+            .filter(|separator| target.contains(*separator))
+        else {
+            return target.parse().ok();
+        };
+
+        let mut groups = target.split(separator).peekable();
+        let first = groups.next()?;
+
+        // The first group is 1 to 3 digits, after an optional sign.
+        let leading = first.trim_start_matches(['+', '-']);
+
+        if !(1..=3).contains(&leading.len()) || !leading.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut stripped = String::with_capacity(target.len());
+        stripped.push_str(first);
+
+        while let Some(group) = groups.next() {
+            // Each group is 3 digits, and only the last can be followed by
+            // anything else, e.g. a decimal part.
+            let digits = group.bytes().take_while(u8::is_ascii_digit).count();
+
+            if digits != 3 || (groups.peek().is_some() && group.len() != 3) {
+                return None;
+            }
+
+            stripped.push_str(group);
+        }
+
+        stripped.parse().ok()
+    }
+}
+
+/// [`Sanitizer`] converts to a Unicode normalization form.
+///
+/// Visually identical strings can be made of different characters, e.g. "é"
+/// can be the single character '\u{E9}' or 'e' followed by the combining
+/// '\u{301}'. Normalizing ensures equivalent strings are stored in the same
+/// form.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(normalize::nfc))]
+/// pub struct Username(String);
+///
+/// // Composes 'e' and '\u{301}' into '\u{E9}'.
+/// assert_eq!(
+///     Username::try_new("caf\u{65}\u{301}").unwrap().into_inner(),
+///     "caf\u{E9}"
+/// );
+/// ```
+///
+/// The compatibility forms also replace characters with their compatibility
+/// equivalents.
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(normalize::nfkc))]
+/// pub struct Username(String);
+///
+/// // Replaces the fullwidth characters with their ASCII equivalents.
+/// assert_eq!(
+///     Username::try_new("\u{FF53}\u{FF45}\u{FF56}\u{FF45}\u{FF4E}")
+///         .unwrap()
+///         .into_inner(),
+///     "seven"
+/// );
+/// ```
+#[cfg(feature = "unicode")]
+pub enum normalize {
+    nfc,
+    nfd,
+    nfkc,
+    nfkd,
+}
+
+#[cfg(feature = "unicode")]
+impl<T> Sanitizer<T> for normalize
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        use unicode_normalization_util::UnicodeNormalization;
+
+        target.with_string(|target| {
+            if is_normalized::from(self).validate(target) {
+                return;
+            }
+
+            *target = match self {
+                Self::nfc => target.nfc().collect(),
+                Self::nfd => target.nfd().collect(),
+                Self::nfkc => target.nfkc().collect(),
+                Self::nfkd => target.nfkd().collect(),
+            };
+        });
+    }
+}
+
+/// [`Validator`] checks if in a Unicode normalization form.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(validate(is_normalized::nfc))]
+/// pub struct Username(String);
+///
+/// // Successfully constructed because the string is in NFC.
+/// assert!(Username::try_new("caf\u{E9}").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not in NFC.
+/// assert!(Username::try_new("caf\u{65}\u{301}").is_err());
+/// ```
+#[cfg(feature = "unicode")]
+pub enum is_normalized {
+    nfc,
+    nfd,
+    nfkc,
+    nfkd,
+}
+
+#[cfg(feature = "unicode")]
+impl From<&normalize> for is_normalized {
+    fn from(normalize: &normalize) -> Self {
+        match normalize {
+            normalize::nfc => Self::nfc,
+            normalize::nfd => Self::nfd,
+            normalize::nfkc => Self::nfkc,
+            normalize::nfkd => Self::nfkd,
+        }
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl<T> Validator<T> for is_normalized
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        use unicode_normalization_util::{is_nfc, is_nfd, is_nfkc, is_nfkd};
+
+        let target = target.as_ref();
+
+        match self {
+            Self::nfc => is_nfc(target),
+            Self::nfd => is_nfd(target),
+            Self::nfkc => is_nfkc(target),
+            Self::nfkd => is_nfkd(target),
+        }
+    }
+}
+
+/// [`Sanitizer`] applies Unicode default case folding.
+///
+/// Unlike [`lowercase`], case folding maps characters so that strings
+/// differing only in case compare equal, e.g. "\u{DF}" folds to "ss".
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(casefold))]
+/// pub struct SearchQuery(String);
+///
+/// // Folds the case.
+/// assert_eq!(
+///     SearchQuery::try_new("Stra\u{DF}e").unwrap().into_inner(),
+///     "strasse"
+/// );
+/// ```
+#[cfg(feature = "unicode")]
+pub struct casefold;
+
+#[cfg(feature = "unicode")]
+impl<T> Sanitizer<T> for casefold
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| *target = caseless_util::default_case_fold_str(target));
+    }
+}
+
+/// Blocklist [`Validator`]s.
+///
+/// Blocklists check that a string does not contain blocked terms, such as
+/// reserved words or offensive language. Matching is case-insensitive and
+/// tolerates common leetspeak substitutions, e.g. "r00t" matches "root".
+///
+/// Blocklists are built when constructed, so consider enabling the `shared`
+/// upgrade to build the blocklist once and share it between instances. The
+/// terms can be loaded at runtime from any iterator of strings.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::string::*, seventy, Newtype};
+///
+/// fn load_offensive_terms() -> Vec<String> {
+///     // Loaded from a file, database, etc.
+///     vec![String::from("darn"), String::from("heck")]
+/// }
+///
+/// #[seventy(
+///     upgrades(shared),
+///     validate(
+///         blocklist::exact(["admin", "root", "support"]),
+///         blocklist::substring(load_offensive_terms())
+///     )
+/// )]
+/// pub struct Username(String);
+///
+/// // Successfully constructed because the username is not blocked.
+/// assert!(Username::try_new("seventy").is_ok());
+///
+/// // Unsuccessfully constructed because the usernames are blocked.
+/// assert!(Username::try_new("R00T").is_err());
+/// assert!(Username::try_new("whatthed4rn").is_err());
+/// ```
+#[cfg(feature = "blocklist")]
+pub mod blocklist {
+    use std::collections::HashSet;
+
+    use aho_corasick_util::AhoCorasick;
+
+    use crate::{builtins::leet::unleet, core::Validator};
+
+    /// [`Validator`] checks if the whole string is not a blocked term.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(validate(blocklist::exact(["admin", "root"])))]
+    /// pub struct WorkspaceName(String);
+    ///
+    /// // Successfully constructed because the name is not blocked.
+    /// assert!(WorkspaceName::try_new("root-workspace").is_ok());
+    ///
+    /// // Unsuccessfully constructed because the name is blocked.
+    /// assert!(WorkspaceName::try_new("Admin").is_err());
+    /// ```
+    pub fn exact<I>(terms: I) -> _exact
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        _exact(
+            terms
+                .into_iter()
+                .map(|term| normalize(term.as_ref()))
+                .collect(),
+        )
+    }
+
+    #[doc(hidden)]
+    pub struct _exact(HashSet<String>);
+
+    impl<T> Validator<T> for _exact
+    where
+        T: AsRef<str>,
+    {
+        fn validate(&self, target: &T) -> bool {
+            !self.0.contains(&normalize(target.as_ref()))
+        }
+    }
+
+    /// [`Validator`] checks if the string does not contain a blocked term.
+    ///
+    /// Backed by [Aho-Corasick](https://docs.rs/aho-corasick), so it stays
+    /// fast for thousands of terms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(validate(blocklist::substring(["darn", "heck"])))]
+    /// pub struct DisplayName(String);
+    ///
+    /// // Successfully constructed because no blocked term is contained.
+    /// assert!(DisplayName::try_new("Seventy").is_ok());
+    ///
+    /// // Unsuccessfully constructed because "heck" is contained.
+    /// assert!(DisplayName::try_new("HeckinSeventy").is_err());
+    /// ```
+    pub fn substring<I>(terms: I) -> _substring
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        _substring(automaton(terms))
+    }
+
+    #[doc(hidden)]
+    pub struct _substring(AhoCorasick);
+
+    impl<T> Validator<T> for _substring
+    where
+        T: AsRef<str>,
+    {
+        fn validate(&self, target: &T) -> bool {
+            !self.0.is_match(&normalize(target.as_ref()))
+        }
+    }
+
+    /// [`Validator`] checks if the string does not contain a blocked term as a
+    /// whole word.
+    ///
+    /// A match is a whole word if it is not surrounded by alphanumeric
+    /// characters. The surrounding characters are checked before
+    /// normalization, so leetspeak symbols such as `!` still end a word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::string::*, seventy, Newtype};
+    ///
+    /// #[seventy(validate(blocklist::word(["ass"])))]
+    /// pub struct Comment(String);
+    ///
+    /// // Successfully constructed because "ass" is not a whole word.
+    /// assert!(Comment::try_new("Classic bass guitar").is_ok());
+    ///
+    /// // Unsuccessfully constructed because "ass" is a whole word.
+    /// assert!(Comment::try_new("Don't be an ASS.").is_err());
+    /// assert!(Comment::try_new("You ass!").is_err());
+    /// ```
+    pub fn word<I>(terms: I) -> _word
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        _word(automaton(terms))
+    }
+
+    #[doc(hidden)]
+    pub struct _word(AhoCorasick);
+
+    impl<T> Validator<T> for _word
+    where
+        T: AsRef<str>,
+    {
+        fn validate(&self, target: &T) -> bool {
+            let target = target.as_ref();
+
+            // Maps each normalized byte to the original character it came from.
+            let mut normalized = String::with_capacity(target.len());
+            let mut origins = Vec::with_capacity(target.len());
+
+            for (start, c) in target.char_indices() {
+                for lowercase in c.to_lowercase() {
+                    normalized.push(unleet(lowercase));
+                    origins.resize(normalized.len(), start..start + c.len_utf8());
+                }
+            }
+
+            let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+
+            !self.0.find_overlapping_iter(&normalized).any(|found| {
+                let start = origins
+                    .get(found.start())
+                    .map_or(target.len(), |origin| origin.start);
+                let end = if found.is_empty() {
+                    start
+                } else {
+                    origins[found.end() - 1].end
+                };
+
+                let before = target[..start].chars().next_back();
+                let after = target[end..].chars().next();
+
+                !is_word_char(before) && !is_word_char(after)
+            })
+        }
+    }
+
+    /// Builds an automaton matching the normalized terms.
+    fn automaton<I>(terms: I) -> AhoCorasick
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        AhoCorasick::new(terms.into_iter().map(|term| normalize(term.as_ref()))).unwrap()
+    }
+
+    /// Converts to lowercase and reverts common leetspeak substitutions.
+    fn normalize(target: &str) -> String {
+        target
+            .chars()
+            .flat_map(char::to_lowercase)
+            .map(unleet)
+            .collect()
     }
 }
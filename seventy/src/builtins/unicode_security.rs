@@ -0,0 +1,176 @@
+//! Unicode security built-ins.
+//!
+//! Follows [Unicode Technical Standard #39](https://www.unicode.org/reports/tr39/)
+//! to detect identifiers that can be visually spoofed, such as `pаypal` with a
+//! Cyrillic 'а' (U+0430).
+
+use std::collections::HashMap;
+
+use unicode_script_util::{Script, UnicodeScript};
+use unicode_security_util::{is_potential_mixed_script_confusable_char, MixedScript};
+
+use crate::{
+    builtins::string::OwnedString,
+    core::{Sanitizer, Validator},
+};
+
+/// [`Validator`] checks if single-script.
+///
+/// A string is single-script if all its characters belong to a common script.
+/// Characters shared between scripts, such as digits and punctuation, belong
+/// to every script. Han, Hiragana, and Katakana are all considered Japanese.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::unicode_security::*, seventy, Newtype};
+///
+/// #[seventy(validate(single_script))]
+/// pub struct Username(String);
+///
+/// // Successfully constructed because the strings are single-script.
+/// assert!(Username::try_new("paypal_70").is_ok());
+/// assert!(Username::try_new("\u{65E5}\u{672C}\u{3054}").is_ok());
+///
+/// // Unsuccessfully constructed because the string mixes Latin and Cyrillic.
+/// assert!(Username::try_new("p\u{430}ypal").is_err());
+/// ```
+pub struct single_script;
+
+impl<T> Validator<T> for single_script
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_ref().is_single_script()
+    }
+}
+
+/// [`Validator`] checks if not mixed-script confusable.
+///
+/// A string is mixed-script confusable if it is not single-script, and a
+/// character outside the string's most common script is confusable with a
+/// character of another script. If multiple scripts are the most common, the
+/// string is valid if any of them can be the most common script. Unlike
+/// [`single_script`], this allows mixing scripts that can't be confused, such
+/// as Latin and Han.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::unicode_security::*, seventy, Newtype};
+///
+/// #[seventy(validate(not_mixed_script_confusable))]
+/// pub struct Username(String);
+///
+/// // Successfully constructed because the strings are not confusable.
+/// assert!(Username::try_new("paypal").is_ok());
+/// assert!(Username::try_new("paypal\u{65E5}\u{672C}").is_ok());
+///
+/// // Unsuccessfully constructed because the Cyrillic '\u{430}' is confusable with the
+/// // Latin 'a'.
+/// assert!(Username::try_new("p\u{430}ypal").is_err());
+/// ```
+pub struct not_mixed_script_confusable;
+
+impl<T> Validator<T> for not_mixed_script_confusable
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref();
+
+        if target.is_single_script() {
+            return true;
+        }
+
+        let is_shared = |script| matches!(script, Script::Common | Script::Inherited);
+
+        let mut counts = HashMap::new();
+        for script in target.chars().map(|c| c.script()) {
+            if !is_shared(script) {
+                *counts.entry(script).or_insert(0usize) += 1;
+            }
+        }
+
+        let max = counts.values().copied().max().unwrap_or_default();
+
+        // If multiple scripts are the most common, any can be the dominant.
+        counts
+            .into_iter()
+            .filter(|(_, count)| *count == max)
+            .any(|(dominant, _)| {
+                target
+                    .chars()
+                    .filter(|c| !is_shared(c.script()) && c.script() != dominant)
+                    .all(|c| !is_potential_mixed_script_confusable_char(c))
+            })
+    }
+}
+
+/// [`Sanitizer`] converts to the confusable skeleton.
+///
+/// Two strings are confusable if they have the same skeleton. The skeleton is
+/// meant for comparison, e.g. stored alongside a username to reject new
+/// usernames that look the same as an existing one, and is not meant to be
+/// displayed.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::unicode_security::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(skeleton))]
+/// pub struct UsernameSkeleton(String);
+///
+/// // The usernames have the same skeleton, so they are confusable.
+/// assert_eq!(
+///     UsernameSkeleton::try_new("p\u{430}ypal").unwrap().into_inner(),
+///     UsernameSkeleton::try_new("paypal").unwrap().into_inner(),
+/// );
+/// ```
+pub struct skeleton;
+
+impl<T> Sanitizer<T> for skeleton
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| *target = unicode_security_util::skeleton(target).collect());
+    }
+}
+
+/// [`Validator`] forwards the confusable skeleton to inner validator.
+///
+/// See [`skeleton`] for details.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{
+///     builtins::{string::*, unicode_security::*},
+///     seventy, Newtype,
+/// };
+///
+/// #[seventy(validate(skeleton_then(not_contains("paypal"))))]
+/// pub struct Username(String);
+///
+/// // Successfully constructed because the skeleton doesn't contain "paypal".
+/// assert!(Username::try_new("seventy").is_ok());
+///
+/// // Unsuccessfully constructed because the skeletons contain "paypal".
+/// assert!(Username::try_new("paypal").is_err());
+/// assert!(Username::try_new("p\u{430}ypal").is_err());
+/// ```
+pub struct skeleton_then<V>(pub V);
+
+impl<T, V> Validator<T> for skeleton_then<V>
+where
+    T: AsRef<str>,
+    V: Validator<String>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = unicode_security_util::skeleton(target.as_ref()).collect();
+        self.0.validate(&target)
+    }
+}
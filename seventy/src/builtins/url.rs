@@ -28,3 +28,204 @@ where
         url_util::Url::parse(target.as_ref()).is_ok()
     }
 }
+
+/// Percent-encoding built-ins.
+pub mod percent {
+    use crate::{
+        builtins::string::OwnedString,
+        core::{Sanitizer, Validator},
+    };
+
+    pub use percent_encoding_util::{AsciiSet, CONTROLS, NON_ALPHANUMERIC};
+
+    /// Characters to encode in a URL path segment.
+    ///
+    /// This is the URL standard's path percent-encode set, plus `/` and `%`.
+    pub const PATH_SEGMENT: &AsciiSet = &CONTROLS
+        .add(b' ')
+        .add(b'"')
+        .add(b'#')
+        .add(b'<')
+        .add(b'>')
+        .add(b'?')
+        .add(b'`')
+        .add(b'{')
+        .add(b'}')
+        .add(b'/')
+        .add(b'%');
+
+    /// [`Sanitizer`] percent-decodes.
+    ///
+    /// Left unchanged if the decoded bytes aren't valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::url::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(percent::decode))]
+    /// pub struct SearchQuery(String);
+    ///
+    /// // Decodes the percent-encoded characters.
+    /// assert_eq!(
+    ///     SearchQuery::try_new("seven%20tea%E2%98%95").unwrap().into_inner(),
+    ///     "seven tea\u{2615}"
+    /// );
+    /// ```
+    pub struct decode;
+
+    impl<T> Sanitizer<T> for decode
+    where
+        T: OwnedString,
+    {
+        fn sanitize(&self, target: &mut T) {
+            target.with_string(|target| {
+                if let Ok(decoded) = percent_encoding_util::percent_decode_str(target).decode_utf8()
+                {
+                    *target = decoded.into_owned();
+                }
+            });
+        }
+    }
+
+    /// [`Sanitizer`] percent-encodes the characters in the set.
+    ///
+    /// Non-ASCII characters are always encoded as UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::url::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(percent::encode(percent::PATH_SEGMENT)))]
+    /// pub struct PathSegment(String);
+    ///
+    /// // Encodes the characters not allowed in a path segment.
+    /// assert_eq!(
+    ///     PathSegment::try_new("seven/tea \u{2615}").unwrap().into_inner(),
+    ///     "seven%2Ftea%20%E2%98%95"
+    /// );
+    /// ```
+    pub struct encode(pub &'static AsciiSet);
+
+    impl<T> Sanitizer<T> for encode
+    where
+        T: OwnedString,
+    {
+        fn sanitize(&self, target: &mut T) {
+            target.with_string(|target| {
+                *target = percent_encoding_util::utf8_percent_encode(target, self.0).to_string();
+            });
+        }
+    }
+
+    /// [`Validator`] checks if well-formed percent-encoding.
+    ///
+    /// Well-formed if every `%` is followed by two hex digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::url::*, seventy, Newtype};
+    ///
+    /// #[seventy(validate(percent::well_formed))]
+    /// pub struct PathSegment(String);
+    ///
+    /// // Successfully constructed because the string is well-formed.
+    /// assert!(PathSegment::try_new("seven%20tea").is_ok());
+    ///
+    /// // Unsuccessfully constructed because the strings are not well-formed.
+    /// assert!(PathSegment::try_new("70%").is_err());
+    /// assert!(PathSegment::try_new("seven%2Gtea").is_err());
+    /// ```
+    pub struct well_formed;
+
+    impl<T> Validator<T> for well_formed
+    where
+        T: AsRef<str>,
+    {
+        fn validate(&self, target: &T) -> bool {
+            let bytes = target.as_ref().as_bytes();
+
+            bytes.iter().enumerate().all(|(index, byte)| {
+                *byte != b'%'
+                    || bytes
+                        .get(index + 1..index + 3)
+                        .is_some_and(|digits| digits.iter().all(u8::is_ascii_hexdigit))
+            })
+        }
+    }
+}
+
+/// Internationalized domain name built-ins.
+pub mod idna {
+    use crate::{builtins::string::OwnedString, core::Sanitizer};
+
+    /// [`Sanitizer`] converts a domain to ASCII (punycode).
+    ///
+    /// The domain is also normalized, e.g. converted to lowercase. Left
+    /// unchanged if the domain is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::url::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(idna::to_ascii))]
+    /// pub struct Hostname(String);
+    ///
+    /// // Converts to punycode.
+    /// assert_eq!(
+    ///     Hostname::try_new("B\u{FC}cher.example").unwrap().into_inner(),
+    ///     "xn--bcher-kva.example"
+    /// );
+    /// ```
+    pub struct to_ascii;
+
+    impl<T> Sanitizer<T> for to_ascii
+    where
+        T: OwnedString,
+    {
+        fn sanitize(&self, target: &mut T) {
+            target.with_string(|target| {
+                if let Ok(ascii) = idna_util::domain_to_ascii(target) {
+                    *target = ascii;
+                }
+            });
+        }
+    }
+
+    /// [`Sanitizer`] converts a domain to Unicode.
+    ///
+    /// The domain is also normalized, e.g. converted to lowercase. Left
+    /// unchanged if the domain is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use seventy::{builtins::url::*, seventy, Newtype};
+    ///
+    /// #[seventy(sanitize(idna::to_unicode))]
+    /// pub struct DisplayHostname(String);
+    ///
+    /// // Converts from punycode.
+    /// assert_eq!(
+    ///     DisplayHostname::try_new("xn--bcher-kva.example").unwrap().into_inner(),
+    ///     "b\u{FC}cher.example"
+    /// );
+    /// ```
+    pub struct to_unicode;
+
+    impl<T> Sanitizer<T> for to_unicode
+    where
+        T: OwnedString,
+    {
+        fn sanitize(&self, target: &mut T) {
+            target.with_string(|target| {
+                if let (unicode, Ok(())) = idna_util::domain_to_unicode(target) {
+                    *target = unicode;
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,269 @@
+#![allow(non_upper_case_globals)]
+
+//! UUID and ULID built-ins.
+
+use std::{borrow::Cow, ops::BitOr};
+
+use crate::{
+    builtins::string::OwnedString,
+    core::{Sanitizer, Validator},
+};
+
+pub use uuid_util::Uuid;
+
+/// Value that can be read as a [`Uuid`].
+///
+/// Implemented for strings, which are parsed in the simple, hyphenated,
+/// braced, or URN form, and for [`Uuid`] itself.
+pub trait AsUuid {
+    /// Reads as a [`Uuid`], returning `None` if not a UUID.
+    fn as_uuid(&self) -> Option<Uuid>;
+}
+
+impl AsUuid for Uuid {
+    fn as_uuid(&self) -> Option<Uuid> {
+        Some(*self)
+    }
+}
+
+impl AsUuid for str {
+    fn as_uuid(&self) -> Option<Uuid> {
+        Uuid::try_parse(self).ok()
+    }
+}
+
+impl AsUuid for &str {
+    fn as_uuid(&self) -> Option<Uuid> {
+        str::as_uuid(self)
+    }
+}
+
+impl AsUuid for String {
+    fn as_uuid(&self) -> Option<Uuid> {
+        str::as_uuid(self)
+    }
+}
+
+impl AsUuid for Box<str> {
+    fn as_uuid(&self) -> Option<Uuid> {
+        str::as_uuid(self)
+    }
+}
+
+impl AsUuid for Cow<'_, str> {
+    fn as_uuid(&self) -> Option<Uuid> {
+        str::as_uuid(self)
+    }
+}
+
+/// [`Validator`] checks if a UUID.
+///
+/// Strings can be in the simple, hyphenated, braced, or URN form, in either
+/// case. Use [`hyphenated_lowercase`] to convert them to a single form.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::uuid::*, seventy, Newtype};
+///
+/// #[seventy(validate(any))]
+/// pub struct OrderId(String);
+///
+/// // Successfully constructed because the strings are UUIDs.
+/// assert!(OrderId::try_new("67e55044-10b1-426f-9247-bb680e5fe0c8").is_ok());
+/// assert!(OrderId::try_new("{67E55044-10B1-426F-9247-BB680E5FE0C8}").is_ok());
+///
+/// // Unsuccessfully constructed because the string is not a UUID.
+/// assert!(OrderId::try_new("67e55044-10b1-426f-9247").is_err());
+/// ```
+pub struct any;
+
+impl<T> Validator<T> for any
+where
+    T: AsUuid,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_uuid().is_some()
+    }
+}
+
+/// Set of UUID versions, used by [`version`].
+///
+/// Combine versions with `|`, e.g. `v4 | v7`.
+#[derive(Clone, Copy)]
+pub struct Versions(u16);
+
+/// UUID version 1, time-based.
+pub const v1: Versions = Versions(1 << 1);
+/// UUID version 2, DCE security.
+pub const v2: Versions = Versions(1 << 2);
+/// UUID version 3, MD5 name-based.
+pub const v3: Versions = Versions(1 << 3);
+/// UUID version 4, random.
+pub const v4: Versions = Versions(1 << 4);
+/// UUID version 5, SHA-1 name-based.
+pub const v5: Versions = Versions(1 << 5);
+/// UUID version 6, reordered time-based.
+pub const v6: Versions = Versions(1 << 6);
+/// UUID version 7, Unix time-based.
+pub const v7: Versions = Versions(1 << 7);
+/// UUID version 8, custom.
+pub const v8: Versions = Versions(1 << 8);
+
+impl BitOr for Versions {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// [`Validator`] checks if a UUID of one of the versions.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::uuid::*, seventy, Newtype};
+///
+/// #[seventy(validate(version(v4 | v7)))]
+/// pub struct OrderId(String);
+///
+/// // Successfully constructed because the UUIDs are version 4 and 7.
+/// assert!(OrderId::try_new("67e55044-10b1-426f-9247-bb680e5fe0c8").is_ok());
+/// assert!(OrderId::try_new("01890a5d-ac96-774b-bcce-b302099a8057").is_ok());
+///
+/// // Unsuccessfully constructed because the UUID is version 1.
+/// assert!(OrderId::try_new("a8098c1a-f86e-11da-bd1a-00112444be1e").is_err());
+///
+/// #[seventy(validate(version(v7)))]
+/// pub struct EventId(Uuid);
+///
+/// // Successfully constructed because the UUID is version 7.
+/// assert!(EventId::try_new(Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap()).is_ok());
+///
+/// // Unsuccessfully constructed because the UUID is version 4.
+/// assert!(EventId::try_new(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()).is_err());
+/// ```
+pub struct version(pub Versions);
+
+impl<T> Validator<T> for version
+where
+    T: AsUuid,
+{
+    fn validate(&self, target: &T) -> bool {
+        target
+            .as_uuid()
+            .is_some_and(|uuid| self.0 .0 & (1 << uuid.get_version_num()) != 0)
+    }
+}
+
+/// [`Validator`] checks if a UUID that is not nil.
+///
+/// The nil UUID is all zeros.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::uuid::*, seventy, Newtype};
+///
+/// #[seventy(validate(not_nil))]
+/// pub struct UserId(Uuid);
+///
+/// // Successfully constructed because the UUID is not nil.
+/// assert!(UserId::try_new(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()).is_ok());
+///
+/// // Unsuccessfully constructed because the UUID is nil.
+/// assert!(UserId::try_new(Uuid::nil()).is_err());
+/// ```
+pub struct not_nil;
+
+impl<T> Validator<T> for not_nil
+where
+    T: AsUuid,
+{
+    fn validate(&self, target: &T) -> bool {
+        target.as_uuid().is_some_and(|uuid| !uuid.is_nil())
+    }
+}
+
+/// [`Sanitizer`] converts a UUID to the hyphenated, lowercase form.
+///
+/// Accepts the simple, hyphenated, braced, or URN form, in either case. Left
+/// unchanged if not a UUID.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::uuid::*, seventy, Newtype};
+///
+/// #[seventy(sanitize(hyphenated_lowercase), validate(any))]
+/// pub struct OrderId(String);
+///
+/// // Converts the braced, URN, and simple forms.
+/// assert_eq!(
+///     OrderId::try_new("{67E55044-10B1-426F-9247-BB680E5FE0C8}").unwrap().into_inner(),
+///     "67e55044-10b1-426f-9247-bb680e5fe0c8"
+/// );
+/// assert_eq!(
+///     OrderId::try_new("urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap().into_inner(),
+///     "67e55044-10b1-426f-9247-bb680e5fe0c8"
+/// );
+/// assert_eq!(
+///     OrderId::try_new("67e5504410b1426f9247bb680e5fe0c8").unwrap().into_inner(),
+///     "67e55044-10b1-426f-9247-bb680e5fe0c8"
+/// );
+/// ```
+pub struct hyphenated_lowercase;
+
+impl<T> Sanitizer<T> for hyphenated_lowercase
+where
+    T: OwnedString,
+{
+    fn sanitize(&self, target: &mut T) {
+        target.with_string(|target| {
+            if let Some(uuid) = target.as_uuid() {
+                *target = uuid.hyphenated().to_string();
+            }
+        });
+    }
+}
+
+/// [`Validator`] checks if a ULID.
+///
+/// A ULID is 26 characters of Crockford's base32, in either case. The first
+/// character is at most `7`, since a ULID is 128 bits.
+///
+/// # Examples
+///
+/// ```
+/// use seventy::{builtins::uuid::*, seventy, Newtype};
+///
+/// #[seventy(validate(ulid))]
+/// pub struct EventId(String);
+///
+/// // Successfully constructed because the string is a ULID.
+/// assert!(EventId::try_new("01ARZ3NDEKTSV4RRFFQ69G5FAV").is_ok());
+///
+/// // Unsuccessfully constructed because the ULID overflows 128 bits.
+/// assert!(EventId::try_new("81ARZ3NDEKTSV4RRFFQ69G5FAV").is_err());
+///
+/// // Unsuccessfully constructed because 'U' is not in Crockford's base32.
+/// assert!(EventId::try_new("01ARZ3NDEKTSV4RRFFQ69G5FAU").is_err());
+/// ```
+pub struct ulid;
+
+impl<T> Validator<T> for ulid
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, target: &T) -> bool {
+        let target = target.as_ref().as_bytes();
+
+        target.len() == 26
+            && matches!(target[0], b'0'..=b'7')
+            && target.iter().all(|byte| {
+                byte.is_ascii_alphanumeric()
+                    && !matches!(byte.to_ascii_uppercase(), b'I' | b'L' | b'O' | b'U')
+            })
+    }
+}